@@ -39,6 +39,12 @@ pub enum Error {
     #[error("missing throttling information of type `{0}`")]
     MissingThrottlingInfo(&'static str),
 
+    #[error("followed more than {0} redirects")]
+    TooManyRedirects(usize),
+
+    #[error("failed to serialize request body")]
+    SerializeBody(#[source] serde_json::error::Error),
+
     #[error("while parsing JSON data from Canvas")]
     #[diagnostic(code(canvas_lms::malformed_json))]
     MalformedJson {
@@ -8,7 +8,10 @@ pub use hyper;
 pub use request::RequestBuilder;
 pub use response::Response;
 
-use hyper::{client::HttpConnector, Method};
+use hyper::{
+    client::{connect::Connect, HttpConnector},
+    header, Body, Method, Request, Uri,
+};
 
 // TODO: currently, we handle throttling only for paginated requests, but this causes problems when we make many paginated requests simultaneously
 //       and doesn't even attempt to handle non-paginated responses, leading to errors in some cases.
@@ -31,6 +34,84 @@ impl<Conn> Client<Conn> {
     pub fn request(&self, method: Method, path: impl Into<String>) -> RequestBuilder<'_, Conn> {
         RequestBuilder::new(self, method, path.into())
     }
+
+    /// Download the file at an absolute URL, such as that of an attachment, following any redirects.
+    ///
+    /// The client only authenticates requests to its own Canvas instance,
+    /// so its token isn't sent to wherever Canvas stores the file.
+    pub async fn download(&self, url: &str) -> Result<Response>
+    where
+        Conn: Connect + Clone + Send + Sync + 'static,
+    {
+        const MAX_REDIRECTS: usize = 5;
+
+        let base_uri: Uri = self.base_uri.parse().map_err(hyper::http::Error::from)?;
+        let mut uri: Uri = url.parse().map_err(hyper::http::Error::from)?;
+        for _ in 0..MAX_REDIRECTS {
+            let mut builder = Request::get(uri.clone());
+            match &self.auth {
+                Some(Auth::Bearer(token)) if same_origin(&uri, &base_uri) => {
+                    builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token))
+                }
+                _ => {}
+            }
+
+            let response = self.hyper.request(builder.body(Body::empty())?).await?;
+            if !response.status().is_redirection() {
+                return Response::from(response).error_for_status();
+            }
+
+            match response
+                .headers()
+                .get(header::LOCATION)
+                .and_then(|location| location.to_str().ok())
+            {
+                Some(location) => uri = resolve_redirect(&uri, location)?,
+                None => return Response::from(response).error_for_status(),
+            }
+        }
+
+        Err(Error::TooManyRedirects(MAX_REDIRECTS))
+    }
+}
+
+/// Whether two URIs share a scheme, host and port, so credentials for one may be sent to the other.
+fn same_origin(uri: &Uri, other: &Uri) -> bool {
+    uri.scheme() == other.scheme()
+        && uri.port_u16() == other.port_u16()
+        && matches!(
+            (uri.host(), other.host()),
+            (Some(host), Some(other_host)) if host.eq_ignore_ascii_case(other_host)
+        )
+}
+
+/// Resolve the `Location` of a redirect, which may be relative, against the URI that was requested.
+fn resolve_redirect(current: &Uri, location: &str) -> Result<Uri> {
+    if let Ok(uri) = location.parse::<Uri>() {
+        if uri.scheme().is_some() {
+            return Ok(uri);
+        }
+    }
+
+    let scheme = current.scheme_str().unwrap_or("https");
+    let authority = current
+        .authority()
+        .map_or("", |authority| authority.as_str());
+    let resolved = if let Some(location) = location.strip_prefix("//") {
+        format!("{}://{}", scheme, location)
+    } else if location.starts_with('/') {
+        format!("{}://{}{}", scheme, authority, location)
+    } else {
+        // a path without a leading slash is relative to the current path's directory
+        let directory = current
+            .path()
+            .rsplit_once('/')
+            .map_or("", |(directory, _)| directory);
+        format!("{}://{}{}/{}", scheme, authority, directory, location)
+    };
+
+    let uri = resolved.parse().map_err(hyper::http::Error::from)?;
+    Ok(uri)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -83,3 +164,44 @@ impl Default for ClientBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+#[test]
+fn only_authenticate_redirects_to_canvas() {
+    let base: Uri = "https://canvas.example.com".parse().unwrap();
+    let current: Uri = "https://canvas.example.com/files/12/download?verifier=abc"
+        .parse()
+        .unwrap();
+
+    for (location, expected, authenticated) in [
+        (
+            "https://canvas.example.com.attacker.net/files/12",
+            "https://canvas.example.com.attacker.net/files/12",
+            false,
+        ),
+        (
+            "http://canvas.example.com/files/12",
+            "http://canvas.example.com/files/12",
+            false,
+        ),
+        (
+            "//files.example.com/12",
+            "https://files.example.com/12",
+            false,
+        ),
+        (
+            "/files/12/download?download_frd=1",
+            "https://canvas.example.com/files/12/download?download_frd=1",
+            true,
+        ),
+        (
+            "preview?inline=1",
+            "https://canvas.example.com/files/12/preview?inline=1",
+            true,
+        ),
+    ] {
+        let uri = resolve_redirect(&current, location).unwrap();
+        assert_eq!(uri.to_string(), expected);
+        assert_eq!(same_origin(&uri, &base), authenticated, "{}", location);
+    }
+}
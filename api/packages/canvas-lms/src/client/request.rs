@@ -1,8 +1,11 @@
 use super::{pagination::Pagination, Auth, Client, Error, Response, Result};
 use hyper::{
-    client::connect::Connect, header, http::request::Builder as HyperRequestBuilder, Body, Method,
-    Request,
+    client::connect::Connect,
+    header::{self, HeaderName, HeaderValue},
+    http::request::Builder as HyperRequestBuilder,
+    Body, Method, Request,
 };
+use serde::Serialize;
 use std::{borrow::Cow, fmt::Write};
 
 #[derive(Debug)]
@@ -104,6 +107,19 @@ impl<'c, Conn> RequestBuilder<'c, Conn> {
             .map_err(Error::from)
     }
 
+    /// Send the request with `body` serialized as JSON.
+    #[inline]
+    pub async fn send_json<B>(self, body: &B) -> Result<Response>
+    where
+        B: Serialize + ?Sized,
+        Conn: Connect + Clone + Send + Sync + 'static,
+    {
+        let body = serde_json::to_vec(body).map_err(Error::SerializeBody)?;
+        self.header(header::CONTENT_TYPE, "application/json")
+            .send_with_body(body.into())
+            .await
+    }
+
     #[inline]
     pub fn paginate(self, per_page: usize) -> Result<Pagination<'c, Conn>>
     where
@@ -134,6 +150,19 @@ impl<'c, Conn> RequestBuilder<'c, Conn> {
         )
     }
 
+    #[inline]
+    #[must_use = "request builder methods create new builders"]
+    pub fn header<K, V>(mut self, key: K, value: V) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<hyper::http::Error>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<hyper::http::Error>,
+    {
+        self.hyper = self.hyper.header(key, value);
+        self
+    }
+
     #[inline]
     #[must_use = "request builder methods create new builders"]
     pub fn query<K, V>(mut self, key: K, value: V) -> Self
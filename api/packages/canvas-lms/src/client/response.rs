@@ -20,6 +20,26 @@ impl Response {
             })
     }
 
+    /// Take the response's body, such as to stream a downloaded file.
+    #[inline]
+    pub fn into_body(self) -> hyper::Body {
+        self.hyper.into_body()
+    }
+
+    /// Turn the response into an error if its status code does not indicate success.
+    #[inline]
+    pub fn error_for_status(self) -> Result<Self> {
+        match self.hyper.status() {
+            code if code.is_success() => Ok(self),
+            StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
+            code => Err(Error::UnknownHttpStatus {
+                code,
+                headers: self.hyper.headers().clone(),
+                response: self,
+            }),
+        }
+    }
+
     #[inline]
     pub fn throttling(&self) -> Throttling {
         Throttling {
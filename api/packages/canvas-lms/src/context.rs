@@ -0,0 +1,117 @@
+use crate::Id;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// A Canvas context which resources like announcements and calendar events belong to.
+///
+/// Contexts are represented by Canvas as "context codes" such as `course_1234`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Context {
+    Course(Id),
+    Group(Id),
+    User(Id),
+    Account(Id),
+}
+
+impl Context {
+    /// The ID of the course, group, user or account.
+    #[inline]
+    pub const fn id(&self) -> Id {
+        match *self {
+            Self::Course(id) | Self::Group(id) | Self::User(id) | Self::Account(id) => id,
+        }
+    }
+
+    /// The type of the context as it appears in context codes.
+    #[inline]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Course(_) => "course",
+            Self::Group(_) => "group",
+            Self::User(_) => "user",
+            Self::Account(_) => "account",
+        }
+    }
+
+    /// The path of the context within Canvas's API, such as `courses/1234`.
+    #[inline]
+    pub fn path(&self) -> String {
+        format!("{}s/{}", self.kind(), self.id())
+    }
+}
+
+impl fmt::Display for Context {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.kind(), self.id())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("malformed context code `{0}`")]
+pub struct ParseContextError(String);
+
+impl FromStr for Context {
+    type Err = ParseContextError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseContextError(s.to_string());
+
+        let (kind, id) = s.rsplit_once('_').ok_or_else(err)?;
+        let id = id.parse().map_err(|_| err())?;
+
+        match kind {
+            "course" => Ok(Self::Course(id)),
+            "group" => Ok(Self::Group(id)),
+            "user" => Ok(Self::User(id)),
+            "account" => Ok(Self::Account(id)),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl Serialize for Context {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Context {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "typescript-definitions")]
+impl typescript_definitions::TypeScriptifyTrait for Context {
+    fn type_script_ify() -> String {
+        "export type Context = string;".to_string()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn context_code_round_trip() {
+    for context in [
+        Context::Course(Id::new(1234)),
+        Context::Group(Id::new(5)),
+        Context::User(Id::new(42)),
+        Context::Account(Id::new(1)),
+    ] {
+        assert_eq!(context.to_string().parse::<Context>().unwrap(), context);
+    }
+
+    assert_eq!(
+        "course_1234".parse::<Context>().unwrap(),
+        Context::Course(Id::new(1234))
+    );
+    assert_eq!(Context::Group(Id::new(5)).path(), "groups/5");
+    assert!("course".parse::<Context>().is_err());
+    assert!("section_12".parse::<Context>().is_err());
+}
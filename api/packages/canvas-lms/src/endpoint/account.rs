@@ -0,0 +1,55 @@
+//! Refer to Canvas's API documentation on [accounts](https://canvas.instructure.com/doc/api/accounts.html)
+//! and [enrollment terms](https://canvas.instructure.com/doc/api/enrollment_terms.html).
+
+use super::Endpoint;
+use crate::{
+    resource::account::{Account, EnrollmentTerm, EnrollmentTerms},
+    Client, Id,
+};
+use hyper::Method;
+
+/// Get a single account, which requires permission to read it.
+#[inline]
+pub fn get_account<Conn>(client: &Client<Conn>, account_id: Id) -> Endpoint<'_, Conn, Account> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/accounts/{}", account_id),
+    )
+}
+
+/// List the accounts the current user administers.
+#[inline]
+pub fn list_accounts<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, Vec<Account>> {
+    Endpoint::new(client, Method::GET, "/api/v1/accounts")
+}
+
+/// List the enrollment terms in a root account.
+///
+/// Canvas paginates the terms within the response object, so only the first page is returned.
+#[inline]
+pub fn list_terms<Conn>(
+    client: &Client<Conn>,
+    account_id: Id,
+) -> Endpoint<'_, Conn, EnrollmentTerms> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/accounts/{}/terms", account_id),
+    )
+    .query("per_page", "100")
+}
+
+/// Get a single enrollment term in a root account.
+#[inline]
+pub fn get_term<Conn>(
+    client: &Client<Conn>,
+    account_id: Id,
+    term_id: Id,
+) -> Endpoint<'_, Conn, EnrollmentTerm> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/accounts/{}/terms/{}", account_id, term_id),
+    )
+}
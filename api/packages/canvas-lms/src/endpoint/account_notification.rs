@@ -0,0 +1,33 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/account_notifications.html).
+
+use super::Endpoint;
+use crate::{resource::AccountNotification, Client, Id};
+use hyper::Method;
+
+/// List the active global notifications of the current user's root account.
+#[inline]
+pub fn list_notifications<Conn>(
+    client: &Client<Conn>,
+) -> Endpoint<'_, Conn, Vec<AccountNotification>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        "/api/v1/accounts/self/account_notifications",
+    )
+}
+
+/// Close a global notification for the current user, so that it is no longer returned by [`list_notifications`].
+#[inline]
+pub fn close_notification<Conn>(
+    client: &Client<Conn>,
+    notification_id: Id,
+) -> Endpoint<'_, Conn, AccountNotification> {
+    Endpoint::new(
+        client,
+        Method::DELETE,
+        format!(
+            "/api/v1/accounts/self/account_notifications/{}",
+            notification_id
+        ),
+    )
+}
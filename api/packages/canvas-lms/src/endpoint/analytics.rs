@@ -0,0 +1,61 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/analytics.html).
+
+use super::Endpoint;
+use crate::{
+    resource::analytics::{AssignmentStatistics, StudentActivity, StudentCommunication},
+    Client, Id,
+};
+use hyper::Method;
+
+/// Get a student's hourly page views and their participations in a course.
+#[inline]
+pub fn get_student_activity<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    student_id: Id,
+) -> Endpoint<'_, Conn, StudentActivity> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/courses/{}/analytics/users/{}/activity",
+            course_id, student_id
+        ),
+    )
+}
+
+/// List how a student's score on each assignment in a course compares to the rest of the course.
+///
+/// Canvas responds with every assignment at once rather than paginating them.
+#[inline]
+pub fn list_student_assignments<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    student_id: Id,
+) -> Endpoint<'_, Conn, Vec<AssignmentStatistics>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/courses/{}/analytics/users/{}/assignments",
+            course_id, student_id
+        ),
+    )
+}
+
+/// Get the number of messages sent between a student and their instructors in a course each day.
+#[inline]
+pub fn get_student_communication<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    student_id: Id,
+) -> Endpoint<'_, Conn, StudentCommunication> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/courses/{}/analytics/users/{}/communication",
+            course_id, student_id
+        ),
+    )
+}
@@ -0,0 +1,48 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/announcements.html).
+
+use super::{format_date, Endpoint};
+use crate::{resource::DiscussionTopic, Client, Context};
+use chrono::{DateTime, Utc};
+use hyper::Method;
+
+/// Filters for [`list_announcements`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AnnouncementFilter {
+    /// The courses to list announcements for.
+    /// Canvas requires at least one.
+    pub context_codes: Vec<Context>,
+    /// Defaults to 14 days before the current date.
+    pub start_date: Option<DateTime<Utc>>,
+    /// Defaults to 28 days after the start date.
+    pub end_date: Option<DateTime<Utc>>,
+    /// Exclude announcements which are locked or delayed.
+    pub active_only: bool,
+    /// Only return the latest announcement for each context.
+    pub latest_only: bool,
+}
+
+/// List announcements across several courses.
+#[inline]
+pub fn list_announcements<Conn>(
+    client: &Client<Conn>,
+    filter: AnnouncementFilter,
+) -> Endpoint<'_, Conn, Vec<DiscussionTopic>> {
+    let mut endpoint = Endpoint::new(client, Method::GET, "/api/v1/announcements")
+        .extend_query(
+            filter
+                .context_codes
+                .into_iter()
+                .map(|context| ("context_codes[]", context.to_string())),
+        )
+        .query("active_only", filter.active_only.to_string())
+        .query("latest_only", filter.latest_only.to_string());
+
+    if let Some(start_date) = filter.start_date {
+        endpoint = endpoint.query("start_date", format_date(start_date));
+    }
+    if let Some(end_date) = filter.end_date {
+        endpoint = endpoint.query("end_date", format_date(end_date));
+    }
+
+    endpoint
+}
@@ -0,0 +1,34 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/appointment_groups.html).
+
+use super::Endpoint;
+use crate::{resource::calendar_event::AppointmentGroup, Client, Id};
+use hyper::Method;
+
+/// List the appointment groups the current user can reserve slots in, along with their slots.
+#[inline]
+pub fn list_reservable<Conn>(
+    client: &Client<Conn>,
+    include_past_appointments: bool,
+) -> Endpoint<'_, Conn, Vec<AppointmentGroup>> {
+    Endpoint::new(client, Method::GET, "/api/v1/appointment_groups")
+        .query("scope", "reservable")
+        .query(
+            "include_past_appointments",
+            include_past_appointments.to_string(),
+        )
+        .include("appointments")
+}
+
+/// Get a single appointment group along with its slots.
+#[inline]
+pub fn get_group<Conn>(
+    client: &Client<Conn>,
+    group_id: Id,
+) -> Endpoint<'_, Conn, AppointmentGroup> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/appointment_groups/{}", group_id),
+    )
+    .include("appointments")
+}
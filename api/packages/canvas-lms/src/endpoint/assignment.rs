@@ -0,0 +1,37 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/assignments.html).
+
+use super::Endpoint;
+use crate::{resource::Assignment, Client, Id};
+use hyper::Method;
+
+/// List the assignments in a course.
+///
+/// Use [`Endpoint::extend_include`] with `submission` and `score_statistics` to include the current user's submission and its statistics.
+#[inline]
+pub fn list_assignments<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, Vec<Assignment>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/assignments", course_id),
+    )
+}
+
+/// List the assignments in a course as a user sees them, which requires being that user or observing them.
+#[inline]
+pub fn list_user_assignments<Conn>(
+    client: &Client<Conn>,
+    user_id: Id,
+    course_id: Id,
+) -> Endpoint<'_, Conn, Vec<Assignment>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/users/{}/courses/{}/assignments",
+            user_id, course_id
+        ),
+    )
+}
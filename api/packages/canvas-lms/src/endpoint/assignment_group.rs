@@ -0,0 +1,38 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/assignment_groups.html).
+
+use super::Endpoint;
+use crate::{resource::assignment_group::AssignmentGroup, Client, Id};
+use hyper::Method;
+
+/// List the assignment groups in a course.
+///
+/// Use [`Endpoint::extend_include`] with `assignments` to include each group's assignments,
+/// and additionally `submission` to include the current user's submission with each assignment.
+#[inline]
+pub fn list_groups<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, Vec<AssignmentGroup>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/assignment_groups", course_id),
+    )
+}
+
+/// Get a single assignment group.
+#[inline]
+pub fn get_group<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    group_id: Id,
+) -> Endpoint<'_, Conn, AssignmentGroup> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/courses/{}/assignment_groups/{}",
+            course_id, group_id
+        ),
+    )
+}
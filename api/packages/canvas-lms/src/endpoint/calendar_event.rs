@@ -0,0 +1,122 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/calendar_events.html).
+
+use super::{format_date, Endpoint};
+use crate::{
+    resource::calendar_event::{AssignmentEvent, CalendarEvent},
+    Client, Context, Id,
+};
+use chrono::{DateTime, Utc};
+use hyper::Method;
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of context codes Canvas accepts in a single request.
+pub const MAX_CONTEXT_CODES: usize = 10;
+
+/// Filters for [`list_events`] and [`list_assignment_events`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CalendarEventFilter {
+    /// The contexts to list events for, of which there may be at most [`MAX_CONTEXT_CODES`].
+    /// Defaults to the current user.
+    pub context_codes: Vec<Context>,
+    /// Defaults to the beginning of the current day.
+    pub start_date: Option<DateTime<Utc>>,
+    /// Defaults to the end of the current day.
+    pub end_date: Option<DateTime<Utc>>,
+    /// Return every event regardless of date, ignoring `start_date` and `end_date`.
+    pub all_events: bool,
+    /// Only return events without a date.
+    pub undated: bool,
+}
+
+/// A reservation of an appointment slot.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewReservation {
+    /// Only relevant to appointment groups with group participants.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub participant_id: Option<Id>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+    /// Cancel any existing reservations in the appointment group which would prevent this one.
+    pub cancel_existing: bool,
+}
+
+#[inline]
+fn list<'c, Conn, T>(
+    client: &'c Client<Conn>,
+    event_type: &'static str,
+    filter: CalendarEventFilter,
+) -> Endpoint<'c, Conn, Vec<T>> {
+    let mut endpoint = Endpoint::new(client, Method::GET, "/api/v1/calendar_events")
+        .query("type", event_type)
+        .extend_query(
+            filter
+                .context_codes
+                .into_iter()
+                .map(|context| ("context_codes[]", context.to_string())),
+        )
+        .query("all_events", filter.all_events.to_string())
+        .query("undated", filter.undated.to_string());
+
+    if let Some(start_date) = filter.start_date {
+        endpoint = endpoint.query("start_date", format_date(start_date));
+    }
+    if let Some(end_date) = filter.end_date {
+        endpoint = endpoint.query("end_date", format_date(end_date));
+    }
+
+    endpoint
+}
+
+/// List calendar events, including appointment slots.
+#[inline]
+pub fn list_events<Conn>(
+    client: &Client<Conn>,
+    filter: CalendarEventFilter,
+) -> Endpoint<'_, Conn, Vec<CalendarEvent>> {
+    list(client, "event", filter)
+}
+
+/// List assignments by their due dates, as they appear on the calendar.
+#[inline]
+pub fn list_assignment_events<Conn>(
+    client: &Client<Conn>,
+    filter: CalendarEventFilter,
+) -> Endpoint<'_, Conn, Vec<AssignmentEvent>> {
+    list(client, "assignment", filter)
+}
+
+/// Get a single calendar event.
+#[inline]
+pub fn get_event<Conn>(client: &Client<Conn>, event_id: Id) -> Endpoint<'_, Conn, CalendarEvent> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/calendar_events/{}", event_id),
+    )
+}
+
+/// Reserve an appointment slot.
+///
+/// Send with [`Endpoint::send_json`] and a [`NewReservation`].
+/// Canvas responds with the reservation, which is itself a calendar event whose parent is the slot.
+#[inline]
+pub fn reserve<Conn>(client: &Client<Conn>, slot_id: Id) -> Endpoint<'_, Conn, CalendarEvent> {
+    Endpoint::new(
+        client,
+        Method::POST,
+        format!("/api/v1/calendar_events/{}/reservations", slot_id),
+    )
+}
+
+/// Cancel a reservation of an appointment slot, given the ID of the reservation rather than of the slot.
+#[inline]
+pub fn unreserve<Conn>(
+    client: &Client<Conn>,
+    reservation_id: Id,
+) -> Endpoint<'_, Conn, CalendarEvent> {
+    Endpoint::new(
+        client,
+        Method::DELETE,
+        format!("/api/v1/calendar_events/{}", reservation_id),
+    )
+}
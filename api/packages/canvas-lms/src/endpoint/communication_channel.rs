@@ -0,0 +1,94 @@
+//! Refer to Canvas's API documentation for [communication channels](https://canvas.instructure.com/doc/api/communication_channels.html)
+//! and [notification preferences](https://canvas.instructure.com/doc/api/notification_preferences.html).
+
+use super::Endpoint;
+use crate::{
+    resource::communication_channel::{
+        CommunicationChannel, NotificationFrequency, NotificationPreferences,
+    },
+    Client, Id,
+};
+use hyper::Method;
+use serde::{Deserialize, Serialize};
+
+/// A change to the frequency of one or more notification preferences.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NotificationPreferenceUpdate {
+    pub notification_preferences: FrequencyChange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FrequencyChange {
+    pub frequency: NotificationFrequency,
+}
+
+impl NotificationPreferenceUpdate {
+    #[inline]
+    pub fn new(frequency: NotificationFrequency) -> Self {
+        Self {
+            notification_preferences: FrequencyChange { frequency },
+        }
+    }
+}
+
+#[inline]
+fn preferences_path(channel_id: Id) -> String {
+    format!(
+        "/api/v1/users/self/communication_channels/{}/notification_preferences",
+        channel_id
+    )
+}
+
+/// List the current user's communication channels.
+#[inline]
+pub fn list_channels<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, Vec<CommunicationChannel>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        "/api/v1/users/self/communication_channels",
+    )
+}
+
+/// List every notification preference of one of the current user's communication channels.
+#[inline]
+pub fn list_preferences<Conn>(
+    client: &Client<Conn>,
+    channel_id: Id,
+) -> Endpoint<'_, Conn, NotificationPreferences> {
+    Endpoint::new(client, Method::GET, preferences_path(channel_id))
+}
+
+/// Update the preference for a single notification, such as `new_announcement`.
+///
+/// Send with [`Endpoint::send_json`] and a [`NotificationPreferenceUpdate`].
+#[inline]
+pub fn update_preference<'c, Conn>(
+    client: &'c Client<Conn>,
+    channel_id: Id,
+    notification: &str,
+) -> Endpoint<'c, Conn, NotificationPreferences> {
+    Endpoint::new(
+        client,
+        Method::PUT,
+        format!("{}/{}", preferences_path(channel_id), notification),
+    )
+}
+
+/// Update the preferences for every notification in a category, such as `announcement`.
+///
+/// Send with [`Endpoint::send_json`] and a [`NotificationPreferenceUpdate`].
+#[inline]
+pub fn update_category<'c, Conn>(
+    client: &'c Client<Conn>,
+    channel_id: Id,
+    category: &str,
+) -> Endpoint<'c, Conn, NotificationPreferences> {
+    Endpoint::new(
+        client,
+        Method::PUT,
+        format!(
+            "/api/v1/users/self/communication_channels/{}/notification_preference_categories/{}",
+            channel_id, category
+        ),
+    )
+}
@@ -0,0 +1,65 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/content_exports.html).
+
+use super::Endpoint;
+use crate::{
+    resource::{content_export::ExportType, ContentExport},
+    Client, Id,
+};
+use hyper::Method;
+use serde::{Deserialize, Serialize};
+
+/// A content export to start.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewContentExport {
+    pub export_type: ExportType,
+    /// Don't notify the user by email once the export finishes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_notifications: Option<bool>,
+}
+
+/// Start exporting a course's content.
+///
+/// Send with [`Endpoint::send_json`] and a [`NewContentExport`],
+/// then poll its [`Progress`](crate::resource::Progress) with [`get_progress`](super::progress::get_progress)
+/// and download its attachment with [`Client::download`] once it is exported.
+#[inline]
+pub fn create_export<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, ContentExport> {
+    Endpoint::new(
+        client,
+        Method::POST,
+        format!("/api/v1/courses/{}/content_exports", course_id),
+    )
+}
+
+/// List the content exports of a course which the current user started.
+#[inline]
+pub fn list_exports<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, Vec<ContentExport>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/content_exports", course_id),
+    )
+}
+
+/// Get a single content export of a course.
+#[inline]
+pub fn get_export<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    export_id: Id,
+) -> Endpoint<'_, Conn, ContentExport> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/courses/{}/content_exports/{}",
+            course_id, export_id
+        ),
+    )
+}
@@ -0,0 +1,166 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/conversations.html).
+
+use super::Endpoint;
+use crate::{
+    resource::conversation::{Conversation, ConversationWorkflowState},
+    Client, Context, Id,
+};
+use hyper::Method;
+use serde::{Deserialize, Serialize};
+
+/// Restricts which conversations [`list_conversations`] returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConversationScope {
+    Unread,
+    Starred,
+    Archived,
+    Sent,
+}
+
+impl ConversationScope {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Unread => "unread",
+            Self::Starred => "starred",
+            Self::Archived => "archived",
+            Self::Sent => "sent",
+        }
+    }
+}
+
+/// The body of a new conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewConversation {
+    /// User IDs, or context codes such as `course_123_students`.
+    pub recipients: Vec<String>,
+    pub subject: String,
+    pub body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_code: Option<Context>,
+    /// Create one conversation shared by every recipient, rather than a separate private conversation with each.
+    pub group_conversation: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachment_ids: Vec<Id>,
+}
+
+/// The body of a reply to a conversation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewMessage {
+    pub body: String,
+    /// Defaults to every participant of the conversation.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recipients: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachment_ids: Vec<Id>,
+}
+
+/// Changes to a conversation, leaving any unset fields untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConversationUpdate {
+    pub conversation: ConversationChanges,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConversationChanges {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workflow_state: Option<ConversationWorkflowState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starred: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscribed: Option<bool>,
+}
+
+impl ConversationUpdate {
+    /// Set the conversation's workflow state.
+    pub fn workflow_state(workflow_state: ConversationWorkflowState) -> Self {
+        Self {
+            conversation: ConversationChanges {
+                workflow_state: Some(workflow_state),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// List the current user's conversations, excluding archived conversations unless `scope` says otherwise.
+#[inline]
+pub fn list_conversations<Conn>(
+    client: &Client<Conn>,
+    scope: Option<ConversationScope>,
+) -> Endpoint<'_, Conn, Vec<Conversation>> {
+    let endpoint =
+        Endpoint::new(client, Method::GET, "/api/v1/conversations").include("participant_avatars");
+
+    match scope {
+        Some(scope) => endpoint.query("scope", scope.as_str()),
+        None => endpoint,
+    }
+}
+
+/// Get a conversation along with its messages.
+///
+/// Canvas marks the conversation as read unless `auto_mark_as_read` is false.
+#[inline]
+pub fn get_conversation<Conn>(
+    client: &Client<Conn>,
+    conversation_id: Id,
+    auto_mark_as_read: bool,
+) -> Endpoint<'_, Conn, Conversation> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/conversations/{}", conversation_id),
+    )
+    .include("participant_avatars")
+    .query("auto_mark_as_read", auto_mark_as_read.to_string())
+}
+
+/// Start a new conversation.
+///
+/// Send with [`Endpoint::send_json`] and a [`NewConversation`].
+/// Canvas responds with every conversation created, of which there are several for non-group conversations with multiple recipients.
+#[inline]
+pub fn create_conversation<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, Vec<Conversation>> {
+    Endpoint::new(client, Method::POST, "/api/v1/conversations")
+}
+
+/// Reply to a conversation.
+///
+/// Send with [`Endpoint::send_json`] and a [`NewMessage`].
+/// Canvas responds with the conversation, whose messages consist of only the new message.
+#[inline]
+pub fn add_message<Conn>(
+    client: &Client<Conn>,
+    conversation_id: Id,
+) -> Endpoint<'_, Conn, Conversation> {
+    Endpoint::new(
+        client,
+        Method::POST,
+        format!("/api/v1/conversations/{}/add_message", conversation_id),
+    )
+}
+
+/// Update a conversation, such as to mark it as read or star it.
+///
+/// Send with [`Endpoint::send_json`] and a [`ConversationUpdate`].
+#[inline]
+pub fn update_conversation<Conn>(
+    client: &Client<Conn>,
+    conversation_id: Id,
+) -> Endpoint<'_, Conn, Conversation> {
+    Endpoint::new(
+        client,
+        Method::PUT,
+        format!("/api/v1/conversations/{}", conversation_id),
+    )
+}
+
+/// Mark all of the current user's conversations as read.
+#[inline]
+pub fn mark_all_read<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, ()> {
+    Endpoint::new(
+        client,
+        Method::POST,
+        "/api/v1/conversations/mark_all_as_read",
+    )
+}
@@ -0,0 +1,176 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/discussion_topics.html).
+
+use super::Endpoint;
+use crate::{
+    resource::discussion_topic::{DiscussionEntry, DiscussionTopic, FullTopicView},
+    Client, Context, Id,
+};
+use hyper::Method;
+use serde::{Deserialize, Serialize};
+
+/// The body of a new entry or reply.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewEntry {
+    pub message: String,
+}
+
+#[inline]
+fn topic_path(context: Context, topic_id: Id) -> String {
+    format!("/api/v1/{}/discussion_topics/{}", context.path(), topic_id)
+}
+
+/// List the discussion topics in a course or group.
+#[inline]
+pub fn list_topics<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+) -> Endpoint<'_, Conn, Vec<DiscussionTopic>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/discussion_topics", context.path()),
+    )
+}
+
+/// Get a single discussion topic.
+#[inline]
+pub fn get_topic<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+    topic_id: Id,
+) -> Endpoint<'_, Conn, DiscussionTopic> {
+    Endpoint::new(client, Method::GET, topic_path(context, topic_id))
+}
+
+/// Get the full, threaded view of a discussion topic's entries.
+///
+/// Canvas may respond with `503 Service Unavailable` while it generates the view, in which case the request should be retried later.
+#[inline]
+pub fn get_full_topic<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+    topic_id: Id,
+) -> Endpoint<'_, Conn, FullTopicView> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("{}/view", topic_path(context, topic_id)),
+    )
+}
+
+/// List the top-level entries of a discussion topic.
+#[inline]
+pub fn list_entries<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+    topic_id: Id,
+) -> Endpoint<'_, Conn, Vec<DiscussionEntry>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("{}/entries", topic_path(context, topic_id)),
+    )
+}
+
+/// List the replies to an entry.
+#[inline]
+pub fn list_replies<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+    topic_id: Id,
+    entry_id: Id,
+) -> Endpoint<'_, Conn, Vec<DiscussionEntry>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "{}/entries/{}/replies",
+            topic_path(context, topic_id),
+            entry_id
+        ),
+    )
+}
+
+/// Post a new top-level entry to a discussion topic.
+///
+/// Send with [`Endpoint::send_json`] and a [`NewEntry`].
+#[inline]
+pub fn post_entry<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+    topic_id: Id,
+) -> Endpoint<'_, Conn, DiscussionEntry> {
+    Endpoint::new(
+        client,
+        Method::POST,
+        format!("{}/entries", topic_path(context, topic_id)),
+    )
+}
+
+/// Post a reply to an entry.
+///
+/// Send with [`Endpoint::send_json`] and a [`NewEntry`].
+#[inline]
+pub fn post_reply<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+    topic_id: Id,
+    entry_id: Id,
+) -> Endpoint<'_, Conn, DiscussionEntry> {
+    Endpoint::new(
+        client,
+        Method::POST,
+        format!(
+            "{}/entries/{}/replies",
+            topic_path(context, topic_id),
+            entry_id
+        ),
+    )
+}
+
+/// Mark a discussion topic itself as read.
+#[inline]
+pub fn mark_topic_read<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+    topic_id: Id,
+) -> Endpoint<'_, Conn, ()> {
+    Endpoint::new(
+        client,
+        Method::PUT,
+        format!("{}/read", topic_path(context, topic_id)),
+    )
+}
+
+/// Mark a discussion topic and all of its entries as read.
+#[inline]
+pub fn mark_all_read<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+    topic_id: Id,
+) -> Endpoint<'_, Conn, ()> {
+    Endpoint::new(
+        client,
+        Method::PUT,
+        format!("{}/read_all", topic_path(context, topic_id)),
+    )
+}
+
+/// Mark a single entry as read.
+#[inline]
+pub fn mark_entry_read<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+    topic_id: Id,
+    entry_id: Id,
+) -> Endpoint<'_, Conn, ()> {
+    Endpoint::new(
+        client,
+        Method::PUT,
+        format!(
+            "{}/entries/{}/read",
+            topic_path(context, topic_id),
+            entry_id
+        ),
+    )
+}
@@ -0,0 +1,71 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/external_tools.html).
+
+use super::Endpoint;
+use crate::{
+    resource::external_tool::{ExternalTool, SessionlessLaunch},
+    Client, Context, Id,
+};
+use hyper::Method;
+
+/// What a [`sessionless_launch`] opens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LaunchTarget {
+    /// A tool from the course's navigation.
+    CourseNavigation { tool_id: Id },
+    /// An assignment completed in an external tool.
+    Assessment { assignment_id: Id },
+}
+
+/// List the external tools installed in a course or group, including those inherited from its accounts.
+#[inline]
+pub fn list_tools<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+) -> Endpoint<'_, Conn, Vec<ExternalTool>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/external_tools", context.path()),
+    )
+    .query("include_parents", "true")
+}
+
+/// Get a single external tool.
+#[inline]
+pub fn get_tool<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+    tool_id: Id,
+) -> Endpoint<'_, Conn, ExternalTool> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/external_tools/{}", context.path(), tool_id),
+    )
+}
+
+/// Get a one-time URL which launches an external tool in a course.
+#[inline]
+pub fn sessionless_launch<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    target: LaunchTarget,
+) -> Endpoint<'_, Conn, SessionlessLaunch> {
+    let endpoint = Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/courses/{}/external_tools/sessionless_launch",
+            course_id
+        ),
+    );
+
+    match target {
+        LaunchTarget::CourseNavigation { tool_id } => endpoint
+            .query("launch_type", "course_navigation")
+            .query("id", tool_id.to_string()),
+        LaunchTarget::Assessment { assignment_id } => endpoint
+            .query("launch_type", "assessment")
+            .query("assignment_id", assignment_id.to_string()),
+    }
+}
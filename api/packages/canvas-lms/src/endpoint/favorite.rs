@@ -0,0 +1,63 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/favorites.html).
+
+use super::Endpoint;
+use crate::{
+    resource::{Course, Favorite, Group},
+    Client, Id,
+};
+use hyper::Method;
+
+/// List the current user's favorite courses, or the courses on their dashboard if they haven't favorited any.
+#[inline]
+pub fn list_favorite_courses<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, Vec<Course>> {
+    Endpoint::new(client, Method::GET, "/api/v1/users/self/favorites/courses")
+}
+
+/// List the current user's favorite groups, or every group they belong to if they haven't favorited any.
+#[inline]
+pub fn list_favorite_groups<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, Vec<Group>> {
+    Endpoint::new(client, Method::GET, "/api/v1/users/self/favorites/groups")
+}
+
+/// Add a course to the current user's favorites.
+#[inline]
+pub fn favorite_course<Conn>(client: &Client<Conn>, course_id: Id) -> Endpoint<'_, Conn, Favorite> {
+    Endpoint::new(
+        client,
+        Method::POST,
+        format!("/api/v1/users/self/favorites/courses/{}", course_id),
+    )
+}
+
+/// Remove a course from the current user's favorites.
+#[inline]
+pub fn unfavorite_course<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, Favorite> {
+    Endpoint::new(
+        client,
+        Method::DELETE,
+        format!("/api/v1/users/self/favorites/courses/{}", course_id),
+    )
+}
+
+/// Add a group to the current user's favorites.
+#[inline]
+pub fn favorite_group<Conn>(client: &Client<Conn>, group_id: Id) -> Endpoint<'_, Conn, Favorite> {
+    Endpoint::new(
+        client,
+        Method::POST,
+        format!("/api/v1/users/self/favorites/groups/{}", group_id),
+    )
+}
+
+/// Remove a group from the current user's favorites.
+#[inline]
+pub fn unfavorite_group<Conn>(client: &Client<Conn>, group_id: Id) -> Endpoint<'_, Conn, Favorite> {
+    Endpoint::new(
+        client,
+        Method::DELETE,
+        format!("/api/v1/users/self/favorites/groups/{}", group_id),
+    )
+}
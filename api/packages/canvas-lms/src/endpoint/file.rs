@@ -0,0 +1,147 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/files.html).
+
+use super::Endpoint;
+use crate::{
+    resource::file::{File, Folder},
+    Client, Context, Id,
+};
+use hyper::Method;
+use serde::{Deserialize, Serialize};
+
+/// A file to upload, which is the first step of Canvas's file upload process.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewFileUpload {
+    pub name: String,
+    /// In bytes.
+    pub size: u64,
+    /// Canvas infers the content type from the name if unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
+/// List every file in a course, group or user context.
+#[inline]
+pub fn list_files<Conn>(client: &Client<Conn>, context: Context) -> Endpoint<'_, Conn, Vec<File>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/files", context.path()),
+    )
+}
+
+/// List the files directly inside a folder.
+#[inline]
+pub fn list_folder_files<Conn>(
+    client: &Client<Conn>,
+    folder_id: Id,
+) -> Endpoint<'_, Conn, Vec<File>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/folders/{}/files", folder_id),
+    )
+}
+
+/// Get a single file.
+#[inline]
+pub fn get_file<Conn>(client: &Client<Conn>, file_id: Id) -> Endpoint<'_, Conn, File> {
+    Endpoint::new(client, Method::GET, format!("/api/v1/files/{}", file_id))
+}
+
+/// List every folder in a context as a flat list.
+///
+/// Use [`FolderTree::build`](crate::resource::file::FolderTree::build) to assemble the hierarchy.
+#[inline]
+pub fn list_folders<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+) -> Endpoint<'_, Conn, Vec<Folder>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/folders", context.path()),
+    )
+}
+
+/// Get the root folder of a context.
+#[inline]
+pub fn get_root_folder<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+) -> Endpoint<'_, Conn, Folder> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/folders/root", context.path()),
+    )
+}
+
+/// Get a single folder.
+#[inline]
+pub fn get_folder<Conn>(client: &Client<Conn>, folder_id: Id) -> Endpoint<'_, Conn, Folder> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/folders/{}", folder_id),
+    )
+}
+
+/// List the folders directly inside a folder.
+#[inline]
+pub fn list_subfolders<Conn>(
+    client: &Client<Conn>,
+    folder_id: Id,
+) -> Endpoint<'_, Conn, Vec<Folder>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/folders/{}/folders", folder_id),
+    )
+}
+
+/// Resolve a path of folder names, such as `lectures/week 1`, relative to a context's root folder.
+///
+/// Each folder name is percent-encoded, so names may contain any character other than `/`.
+/// Canvas responds with every folder along the path, starting with the root folder.
+#[inline]
+pub fn resolve_folder_path<'c, Conn>(
+    client: &'c Client<Conn>,
+    context: Context,
+    path: &str,
+) -> Endpoint<'c, Conn, Vec<Folder>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/{}/folders/by_path/{}",
+            context.path(),
+            path.trim_matches('/')
+                .split('/')
+                .map(encode_path_segment)
+                .collect::<Vec<_>>()
+                .join("/")
+        ),
+    )
+}
+
+/// Percent-encode a segment of a URI's path, so characters such as spaces, `?` and `#` are taken literally.
+fn encode_path_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+#[test]
+fn encode_folder_path_segments() {
+    assert_eq!(encode_path_segment("week 1"), "week%201");
+    assert_eq!(encode_path_segment("q&a?#1"), "q%26a%3F%231");
+    assert_eq!(encode_path_segment("résumé"), "r%C3%A9sum%C3%A9");
+}
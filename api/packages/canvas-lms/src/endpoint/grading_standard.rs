@@ -0,0 +1,36 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/grading_standards.html).
+
+use super::Endpoint;
+use crate::{resource::GradingStandard, Client, Context, Id};
+use hyper::Method;
+
+/// List the grading standards available in a course or account, including those inherited from parent accounts.
+#[inline]
+pub fn list_standards<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+) -> Endpoint<'_, Conn, Vec<GradingStandard>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/grading_standards", context.path()),
+    )
+}
+
+/// Get a single grading standard available in a course or account.
+#[inline]
+pub fn get_standard<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+    standard_id: Id,
+) -> Endpoint<'_, Conn, GradingStandard> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/{}/grading_standards/{}",
+            context.path(),
+            standard_id
+        ),
+    )
+}
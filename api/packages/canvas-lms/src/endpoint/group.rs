@@ -0,0 +1,72 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/groups.html).
+
+use super::Endpoint;
+use crate::{
+    resource::group::{Group, GroupCategory, GroupMembership},
+    Client, Id,
+};
+use hyper::Method;
+
+/// List the groups the current user belongs to, across every course and account.
+#[inline]
+pub fn list_own_groups<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, Vec<Group>> {
+    Endpoint::new(client, Method::GET, "/api/v1/users/self/groups")
+}
+
+/// List the groups in a course which are visible to the current user.
+#[inline]
+pub fn list_course_groups<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, Vec<Group>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/groups", course_id),
+    )
+}
+
+/// Get a single group.
+#[inline]
+pub fn get_group<Conn>(client: &Client<Conn>, group_id: Id) -> Endpoint<'_, Conn, Group> {
+    Endpoint::new(client, Method::GET, format!("/api/v1/groups/{}", group_id))
+}
+
+/// List the group categories in a course.
+#[inline]
+pub fn list_categories<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, Vec<GroupCategory>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/group_categories", course_id),
+    )
+}
+
+/// List the memberships of a group.
+#[inline]
+pub fn list_memberships<Conn>(
+    client: &Client<Conn>,
+    group_id: Id,
+) -> Endpoint<'_, Conn, Vec<GroupMembership>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/groups/{}/memberships", group_id),
+    )
+}
+
+/// Get the current user's membership in a group.
+#[inline]
+pub fn get_own_membership<Conn>(
+    client: &Client<Conn>,
+    group_id: Id,
+) -> Endpoint<'_, Conn, GroupMembership> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/groups/{}/users/self", group_id),
+    )
+}
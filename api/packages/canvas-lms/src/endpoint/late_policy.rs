@@ -0,0 +1,18 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/late_policy.html).
+
+use super::Endpoint;
+use crate::{resource::late_policy::LatePolicyResponse, Client, Id};
+use hyper::Method;
+
+/// Get a course's late policy.
+#[inline]
+pub fn get_late_policy<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, LatePolicyResponse> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/late_policy", course_id),
+    )
+}
@@ -0,0 +1,193 @@
+//! Typed Canvas API endpoints.
+//!
+//! Each submodule corresponds to a group of endpoints in Canvas's API documentation,
+//! and each function within it returns an [`Endpoint`] which knows the type Canvas responds with.
+
+use crate::client::{pagination::Items, Client, RequestBuilder, Response, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures::{future::BoxFuture, prelude::*};
+use hyper::{client::connect::Connect, Method};
+use serde::{de::DeserializeOwned, Serialize};
+use std::marker::PhantomData;
+
+pub mod account;
+pub mod account_notification;
+pub mod analytics;
+pub mod announcement;
+pub mod appointment_group;
+pub mod assignment;
+pub mod assignment_group;
+pub mod calendar_event;
+pub mod communication_channel;
+pub mod content_export;
+pub mod conversation;
+pub mod discussion_topic;
+pub mod external_tool;
+pub mod favorite;
+pub mod file;
+pub mod grading_standard;
+pub mod group;
+pub mod late_policy;
+pub mod outcome;
+pub mod page;
+pub mod peer_review;
+pub mod planner;
+pub mod progress;
+pub mod quiz;
+pub mod rubric;
+pub mod section;
+pub mod submission;
+pub mod user;
+
+/// A request to a Canvas API endpoint responding with a `T`.
+#[derive(Debug)]
+#[must_use = "endpoints do nothing unless sent or paginated"]
+pub struct Endpoint<'c, Conn, T> {
+    request: RequestBuilder<'c, Conn>,
+    _response: PhantomData<fn() -> T>,
+}
+
+impl<'c, Conn, T> Endpoint<'c, Conn, T> {
+    #[inline]
+    pub fn new(client: &'c Client<Conn>, method: Method, path: impl Into<String>) -> Self {
+        Self::from_request(client.request(method, path))
+    }
+
+    #[inline]
+    pub fn from_request(request: RequestBuilder<'c, Conn>) -> Self {
+        Self {
+            request,
+            _response: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn into_request(self) -> RequestBuilder<'c, Conn> {
+        self.request
+    }
+
+    #[inline]
+    fn map_request<F>(self, f: F) -> Self
+    where
+        F: FnOnce(RequestBuilder<'c, Conn>) -> RequestBuilder<'c, Conn>,
+    {
+        Self::from_request(f(self.request))
+    }
+
+    #[inline]
+    #[must_use = "endpoint methods create new endpoints"]
+    pub fn query<K, V>(self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.map_request(|req| req.query(key, value))
+    }
+
+    #[inline]
+    #[must_use = "endpoint methods create new endpoints"]
+    pub fn extend_query<K, V, I>(self, iter: I) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        self.map_request(|req| req.extend_query(iter))
+    }
+
+    #[inline]
+    #[must_use = "endpoint methods create new endpoints"]
+    pub fn include(self, val: impl ToString) -> Self {
+        self.map_request(|req| req.include(val))
+    }
+
+    #[inline]
+    #[must_use = "endpoint methods create new endpoints"]
+    pub fn extend_include<S, I>(self, iter: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        self.map_request(|req| req.extend_include(iter))
+    }
+
+    /// Send the request, discarding the body of a successful response.
+    ///
+    /// This is useful for endpoints like `PUT .../read` which respond with `204 No Content`.
+    #[inline]
+    pub async fn execute(self) -> Result<()>
+    where
+        Conn: Connect + Clone + Send + Sync + 'static,
+    {
+        self.request.send().await?.error_for_status().map(drop)
+    }
+}
+
+impl<'c, Conn, T: DeserializeOwned> Endpoint<'c, Conn, T> {
+    #[inline]
+    pub async fn send(self) -> Result<T>
+    where
+        Conn: Connect + Clone + Send + Sync + 'static,
+    {
+        self.request
+            .send()
+            .await?
+            .error_for_status()?
+            .deserialize()
+            .await
+    }
+
+    #[inline]
+    pub async fn send_json<B>(self, body: &B) -> Result<T>
+    where
+        B: Serialize + ?Sized,
+        Conn: Connect + Clone + Send + Sync + 'static,
+    {
+        self.request
+            .send_json(body)
+            .await?
+            .error_for_status()?
+            .deserialize()
+            .await
+    }
+}
+
+impl<'c, Conn, T> Endpoint<'c, Conn, Vec<T>>
+where
+    Conn: Connect + Clone + Send + Sync + Unpin + 'static,
+    T: DeserializeOwned + Send + Unpin + 'static,
+{
+    /// Paginate over the endpoint, yielding each page as it is deserialized.
+    #[inline]
+    pub fn pages_owned<'a>(
+        self,
+        per_page: usize,
+    ) -> Result<impl Stream<Item = Result<Vec<T>>> + Unpin + 'a> {
+        Ok(self
+            .request
+            .paginate_owned(per_page)?
+            .and_then(deserialize_page::<T>))
+    }
+
+    /// Paginate over the endpoint, yielding each item individually.
+    #[inline]
+    pub fn items_owned<'a>(self, per_page: usize) -> Result<Items<'a, Conn, T>> {
+        Ok(self.request.paginate_owned(per_page)?.items())
+    }
+}
+
+// NOTE: this is a function rather than a closure so that the stream returned by
+//       [`Endpoint::pages_owned`] doesn't capture the lifetime of the endpoint
+#[inline]
+fn deserialize_page<T>(response: Response) -> BoxFuture<'static, Result<Vec<T>>>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    response.deserialize().boxed()
+}
+
+/// Format a date in the ISO 8601 format expected by Canvas's query parameters.
+#[inline]
+pub(crate) fn format_date(date: DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
@@ -0,0 +1,80 @@
+//! Refer to Canvas's API documentation on [outcomes](https://canvas.instructure.com/doc/api/outcomes.html),
+//! [outcome groups](https://canvas.instructure.com/doc/api/outcome_groups.html)
+//! and [outcome results](https://canvas.instructure.com/doc/api/outcome_results.html).
+
+use super::Endpoint;
+use crate::{
+    resource::outcome::{Outcome, OutcomeGroup, OutcomeLink, OutcomeResults, OutcomeRollups},
+    Client, Context, Id,
+};
+use hyper::Method;
+
+/// List every outcome group in a course or account, from which the outcome tree can be rebuilt using each group's parent.
+#[inline]
+pub fn list_groups<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+) -> Endpoint<'_, Conn, Vec<OutcomeGroup>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/outcome_groups", context.path()),
+    )
+}
+
+/// List every outcome in a course or account along with the group it is placed in.
+#[inline]
+pub fn list_links<Conn>(
+    client: &Client<Conn>,
+    context: Context,
+) -> Endpoint<'_, Conn, Vec<OutcomeLink>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/outcome_group_links", context.path()),
+    )
+    .query("outcome_style", "full")
+}
+
+/// Get a single outcome.
+#[inline]
+pub fn get_outcome<Conn>(client: &Client<Conn>, outcome_id: Id) -> Endpoint<'_, Conn, Outcome> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/outcomes/{}", outcome_id),
+    )
+}
+
+/// List a student's results for every outcome in a course.
+///
+/// Canvas paginates the results within the response object, so only the first page is returned.
+#[inline]
+pub fn list_results<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    user_id: Id,
+) -> Endpoint<'_, Conn, OutcomeResults> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/outcome_results", course_id),
+    )
+    .query("user_ids[]", user_id.to_string())
+    .query("per_page", "100")
+}
+
+/// Get a student's combined score for each outcome in a course.
+#[inline]
+pub fn list_rollups<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    user_id: Id,
+) -> Endpoint<'_, Conn, OutcomeRollups> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/outcome_rollups", course_id),
+    )
+    .query("user_ids[]", user_id.to_string())
+}
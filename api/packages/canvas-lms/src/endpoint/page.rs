@@ -0,0 +1,39 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/pages.html).
+
+use super::Endpoint;
+use crate::{resource::page::Page, Client, Context};
+use hyper::Method;
+
+/// List the pages in a course or group, without their bodies.
+#[inline]
+pub fn list_pages<Conn>(client: &Client<Conn>, context: Context) -> Endpoint<'_, Conn, Vec<Page>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/pages", context.path()),
+    )
+}
+
+/// Get a single page along with its body, given either its URL slug or its ID.
+#[inline]
+pub fn get_page<'c, Conn>(
+    client: &'c Client<Conn>,
+    context: Context,
+    url_or_id: &str,
+) -> Endpoint<'c, Conn, Page> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/pages/{}", context.path(), url_or_id),
+    )
+}
+
+/// Get the front page of a course or group.
+#[inline]
+pub fn get_front_page<Conn>(client: &Client<Conn>, context: Context) -> Endpoint<'_, Conn, Page> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/{}/front_page", context.path()),
+    )
+}
@@ -0,0 +1,42 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/peer_reviews.html).
+
+use super::Endpoint;
+use crate::{resource::PeerReview, Client, Id};
+use hyper::Method;
+
+/// List the peer reviews for an assignment, of which students can only see those assigned to them.
+#[inline]
+pub fn list_reviews<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    assignment_id: Id,
+) -> Endpoint<'_, Conn, Vec<PeerReview>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/courses/{}/assignments/{}/peer_reviews",
+            course_id, assignment_id
+        ),
+    )
+    .extend_include(["user", "submission_comments"])
+}
+
+/// List the peer reviews of a submission, such as those of the current user's own submission.
+#[inline]
+pub fn list_submission_reviews<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    assignment_id: Id,
+    submission_id: Id,
+) -> Endpoint<'_, Conn, Vec<PeerReview>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/courses/{}/assignments/{}/submissions/{}/peer_reviews",
+            course_id, assignment_id, submission_id
+        ),
+    )
+    .extend_include(["user", "submission_comments"])
+}
@@ -0,0 +1,221 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/planner.html).
+
+use super::{format_date, Endpoint};
+use crate::{
+    resource::planner::{PlannableType, PlannerItem, PlannerNote, PlannerOverride},
+    Client, Context, Id,
+};
+use chrono::{DateTime, Utc};
+use hyper::Method;
+use serde::{Deserialize, Serialize};
+
+/// Filters for [`list_items`] and [`list_notes`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PlannerFilter {
+    /// Defaults to every context the user belongs to.
+    pub context_codes: Vec<Context>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+}
+
+impl PlannerFilter {
+    fn apply<Conn, T>(self, mut endpoint: Endpoint<'_, Conn, T>) -> Endpoint<'_, Conn, T> {
+        endpoint = endpoint.extend_query(
+            self.context_codes
+                .into_iter()
+                .map(|context| ("context_codes[]", context.to_string())),
+        );
+        if let Some(start_date) = self.start_date {
+            endpoint = endpoint.query("start_date", format_date(start_date));
+        }
+        if let Some(end_date) = self.end_date {
+            endpoint = endpoint.query("end_date", format_date(end_date));
+        }
+        endpoint
+    }
+}
+
+/// Restricts which items [`list_items`] returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlannerItemState {
+    NewActivity,
+    IncompleteItems,
+    CompleteItems,
+}
+
+impl PlannerItemState {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::NewActivity => "new_activity",
+            Self::IncompleteItems => "incomplete_items",
+            Self::CompleteItems => "complete_items",
+        }
+    }
+}
+
+/// The body of a new planner note.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewPlannerNote {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    pub todo_date: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub course_id: Option<Id>,
+    /// The object, such as an assignment, which the note is about.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked_object_type: Option<PlannableType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linked_object_id: Option<Id>,
+}
+
+/// Changes to an existing planner note, leaving any unset fields untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlannerNoteUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todo_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub course_id: Option<Id>,
+}
+
+/// The body of a new planner override.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewPlannerOverride {
+    pub plannable_type: PlannableType,
+    pub plannable_id: Id,
+    pub marked_complete: bool,
+    pub dismissed: bool,
+}
+
+/// Changes to an existing planner override, leaving any unset fields untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlannerOverrideUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marked_complete: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismissed: Option<bool>,
+}
+
+/// List the items on the current user's planner.
+#[inline]
+pub fn list_items<Conn>(
+    client: &Client<Conn>,
+    filter: PlannerFilter,
+    state: Option<PlannerItemState>,
+) -> Endpoint<'_, Conn, Vec<PlannerItem>> {
+    let endpoint = filter.apply(Endpoint::new(client, Method::GET, "/api/v1/planner/items"));
+
+    match state {
+        Some(state) => endpoint.query("filter", state.as_str()),
+        None => endpoint,
+    }
+}
+
+/// List the current user's planner notes.
+#[inline]
+pub fn list_notes<Conn>(
+    client: &Client<Conn>,
+    filter: PlannerFilter,
+) -> Endpoint<'_, Conn, Vec<PlannerNote>> {
+    filter.apply(Endpoint::new(client, Method::GET, "/api/v1/planner_notes"))
+}
+
+/// Get a single planner note.
+#[inline]
+pub fn get_note<Conn>(client: &Client<Conn>, note_id: Id) -> Endpoint<'_, Conn, PlannerNote> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/planner_notes/{}", note_id),
+    )
+}
+
+/// Create a planner note.
+///
+/// Send with [`Endpoint::send_json`] and a [`NewPlannerNote`].
+#[inline]
+pub fn create_note<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, PlannerNote> {
+    Endpoint::new(client, Method::POST, "/api/v1/planner_notes")
+}
+
+/// Update a planner note.
+///
+/// Send with [`Endpoint::send_json`] and a [`PlannerNoteUpdate`].
+#[inline]
+pub fn update_note<Conn>(client: &Client<Conn>, note_id: Id) -> Endpoint<'_, Conn, PlannerNote> {
+    Endpoint::new(
+        client,
+        Method::PUT,
+        format!("/api/v1/planner_notes/{}", note_id),
+    )
+}
+
+/// Delete a planner note, responding with the deleted note.
+#[inline]
+pub fn delete_note<Conn>(client: &Client<Conn>, note_id: Id) -> Endpoint<'_, Conn, PlannerNote> {
+    Endpoint::new(
+        client,
+        Method::DELETE,
+        format!("/api/v1/planner_notes/{}", note_id),
+    )
+}
+
+/// List the current user's planner overrides.
+#[inline]
+pub fn list_overrides<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, Vec<PlannerOverride>> {
+    Endpoint::new(client, Method::GET, "/api/v1/planner/overrides")
+}
+
+/// Get a single planner override.
+#[inline]
+pub fn get_override<Conn>(
+    client: &Client<Conn>,
+    override_id: Id,
+) -> Endpoint<'_, Conn, PlannerOverride> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/planner/overrides/{}", override_id),
+    )
+}
+
+/// Create a planner override.
+///
+/// Send with [`Endpoint::send_json`] and a [`NewPlannerOverride`].
+/// Canvas refuses to create a second override for the same plannable, so use [`update_override`] if one exists.
+#[inline]
+pub fn create_override<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, PlannerOverride> {
+    Endpoint::new(client, Method::POST, "/api/v1/planner/overrides")
+}
+
+/// Update a planner override.
+///
+/// Send with [`Endpoint::send_json`] and a [`PlannerOverrideUpdate`].
+#[inline]
+pub fn update_override<Conn>(
+    client: &Client<Conn>,
+    override_id: Id,
+) -> Endpoint<'_, Conn, PlannerOverride> {
+    Endpoint::new(
+        client,
+        Method::PUT,
+        format!("/api/v1/planner/overrides/{}", override_id),
+    )
+}
+
+/// Delete a planner override, responding with the deleted override.
+#[inline]
+pub fn delete_override<Conn>(
+    client: &Client<Conn>,
+    override_id: Id,
+) -> Endpoint<'_, Conn, PlannerOverride> {
+    Endpoint::new(
+        client,
+        Method::DELETE,
+        format!("/api/v1/planner/overrides/{}", override_id),
+    )
+}
@@ -0,0 +1,15 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/progress.html).
+
+use super::Endpoint;
+use crate::{resource::Progress, Client, Id};
+use hyper::Method;
+
+/// Get the progress of an asynchronous job.
+#[inline]
+pub fn get_progress<Conn>(client: &Client<Conn>, progress_id: Id) -> Endpoint<'_, Conn, Progress> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/progress/{}", progress_id),
+    )
+}
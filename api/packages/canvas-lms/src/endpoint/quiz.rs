@@ -0,0 +1,98 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/quizzes.html).
+
+use super::Endpoint;
+use crate::{
+    resource::quiz::{Quiz, QuizQuestion, QuizSubmissions},
+    Client, Id,
+};
+use hyper::Method;
+
+#[inline]
+fn quiz_path(course_id: Id, quiz_id: Id) -> String {
+    format!("/api/v1/courses/{}/quizzes/{}", course_id, quiz_id)
+}
+
+/// List the quizzes in a course.
+#[inline]
+pub fn list_quizzes<Conn>(client: &Client<Conn>, course_id: Id) -> Endpoint<'_, Conn, Vec<Quiz>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/quizzes", course_id),
+    )
+}
+
+/// Get a single quiz.
+#[inline]
+pub fn get_quiz<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    quiz_id: Id,
+) -> Endpoint<'_, Conn, Quiz> {
+    Endpoint::new(client, Method::GET, quiz_path(course_id, quiz_id))
+}
+
+/// List the submissions for a quiz.
+///
+/// Students will only receive their own submissions.
+/// Canvas wraps the submissions in an object, so this endpoint can't be paginated with [`Endpoint::pages_owned`];
+/// instead, it requests as many submissions as Canvas allows in a single page.
+#[inline]
+pub fn list_submissions<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    quiz_id: Id,
+) -> Endpoint<'_, Conn, QuizSubmissions> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("{}/submissions", quiz_path(course_id, quiz_id)),
+    )
+    .query("per_page", "100")
+}
+
+/// Get the current user's submission for a quiz.
+#[inline]
+pub fn get_own_submission<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    quiz_id: Id,
+) -> Endpoint<'_, Conn, QuizSubmissions> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("{}/submission", quiz_path(course_id, quiz_id)),
+    )
+}
+
+/// List the questions in a quiz.
+///
+/// This requires permission to edit the quiz; students should use [`list_submission_questions`] instead.
+#[inline]
+pub fn list_questions<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    quiz_id: Id,
+) -> Endpoint<'_, Conn, Vec<QuizQuestion>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("{}/questions", quiz_path(course_id, quiz_id)),
+    )
+}
+
+/// List the questions in a quiz as they were presented in one attempt of a submission.
+///
+/// Canvas only permits this once the results of the attempt are visible to the user.
+#[inline]
+pub fn list_submission_questions<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    quiz_id: Id,
+    quiz_submission_id: Id,
+    attempt: u32,
+) -> Endpoint<'_, Conn, Vec<QuizQuestion>> {
+    list_questions(client, course_id, quiz_id)
+        .query("quiz_submission_id", quiz_submission_id.to_string())
+        .query("quiz_submission_attempt", attempt.to_string())
+}
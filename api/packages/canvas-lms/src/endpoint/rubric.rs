@@ -0,0 +1,29 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/rubrics.html).
+
+use super::Endpoint;
+use crate::{resource::rubric::Rubric, Client, Id};
+use hyper::Method;
+
+/// List the rubrics in a course.
+#[inline]
+pub fn list_rubrics<Conn>(client: &Client<Conn>, course_id: Id) -> Endpoint<'_, Conn, Vec<Rubric>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/rubrics", course_id),
+    )
+}
+
+/// Get a single rubric.
+#[inline]
+pub fn get_rubric<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    rubric_id: Id,
+) -> Endpoint<'_, Conn, Rubric> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/rubrics/{}", course_id, rubric_id),
+    )
+}
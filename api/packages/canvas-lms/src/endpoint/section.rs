@@ -0,0 +1,32 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/sections.html).
+
+use super::Endpoint;
+use crate::{resource::section::Section, Client, Id};
+use hyper::Method;
+
+/// List the sections of a course.
+#[inline]
+pub fn list_sections<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, Vec<Section>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/sections", course_id),
+    )
+}
+
+/// Get a single section.
+#[inline]
+pub fn get_section<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    section_id: Id,
+) -> Endpoint<'_, Conn, Section> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/sections/{}", course_id, section_id),
+    )
+}
@@ -0,0 +1,249 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/submissions.html).
+
+use super::Endpoint;
+use crate::{
+    resource::{file::FileUpload, Progress, RubricAssessment, Submission},
+    Client, Id,
+};
+use hyper::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Changes to a submission, leaving any unset fields untouched.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<NewSubmissionComment>,
+    /// Requires permission to grade the assignment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submission: Option<GradeUpdate>,
+    /// Replaces the submission's rubric assessment, and requires permission to grade the assignment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rubric_assessment: Option<RubricAssessment>,
+}
+
+/// A grade to give a submission.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GradeUpdate {
+    /// A score such as `87`, a percentage such as `87%`, a letter grade or `pass`, depending on the assignment's grading type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub posted_grade: Option<String>,
+    /// Excuse the student from the assignment, or stop excusing them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excuse: Option<bool>,
+}
+
+/// Grades for many students' submissions to an assignment at once.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BulkGradeUpdate {
+    /// Keyed by the ID of each student.
+    pub grade_data: HashMap<Id, GradeData>,
+}
+
+/// The grade, comment and rubric assessment to give a single student in a [`BulkGradeUpdate`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GradeData {
+    /// As in [`GradeUpdate::posted_grade`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub posted_grade: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excuse: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rubric_assessment: Option<RubricAssessment>,
+}
+
+/// A comment to leave on a submission.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewSubmissionComment {
+    pub text_comment: String,
+    /// Send the comment to every member of the submitting group, rather than only the submitter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_comment: Option<bool>,
+    /// Files uploaded with [`upload_comment_file`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub file_ids: Vec<Id>,
+}
+
+impl SubmissionUpdate {
+    /// Leave a comment on the submission.
+    pub fn comment(text_comment: impl Into<String>) -> Self {
+        Self::comment_with_files(text_comment, Vec::new())
+    }
+
+    /// Leave a comment on the submission with files attached.
+    pub fn comment_with_files(text_comment: impl Into<String>, file_ids: Vec<Id>) -> Self {
+        Self {
+            comment: Some(NewSubmissionComment {
+                text_comment: text_comment.into(),
+                file_ids,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Grade the submission.
+    pub fn grade(posted_grade: impl Into<String>) -> Self {
+        Self {
+            submission: Some(GradeUpdate {
+                posted_grade: Some(posted_grade.into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// List every student's submission for an assignment, which requires permission to grade it.
+///
+/// Use [`Endpoint::include`] with `user` to include each submission's student.
+#[inline]
+pub fn list_submissions<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    assignment_id: Id,
+) -> Endpoint<'_, Conn, Vec<Submission>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/courses/{}/assignments/{}/submissions",
+            course_id, assignment_id
+        ),
+    )
+}
+
+/// List the current user's submissions for every assignment in a course.
+///
+/// Use [`Endpoint::include`] with `rubric_assessment` to include each submission's rubric assessment.
+#[inline]
+pub fn list_own_submissions<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, Vec<Submission>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/students/submissions", course_id),
+    )
+    .query("student_ids[]", "self")
+}
+
+/// List a student's submissions for every assignment in a course, which requires being that student or observing them.
+///
+/// Use [`Endpoint::extend_include`] as with [`list_own_submissions`].
+#[inline]
+pub fn list_student_submissions<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    student_id: Id,
+) -> Endpoint<'_, Conn, Vec<Submission>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/students/submissions", course_id),
+    )
+    .query("student_ids[]", student_id.to_string())
+}
+
+/// Get the current user's submission for an assignment.
+///
+/// Use [`Endpoint::extend_include`] with `submission_comments`, `submission_history` and `rubric_assessment` to include the feedback on it.
+#[inline]
+pub fn get_own_submission<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    assignment_id: Id,
+) -> Endpoint<'_, Conn, Submission> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/courses/{}/assignments/{}/submissions/self",
+            course_id, assignment_id
+        ),
+    )
+}
+
+/// Get a user's submission for an assignment, which requires being that user, observing them or grading the assignment.
+///
+/// Use [`Endpoint::extend_include`] as with [`get_own_submission`].
+#[inline]
+pub fn get_submission<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    assignment_id: Id,
+    user_id: Id,
+) -> Endpoint<'_, Conn, Submission> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!(
+            "/api/v1/courses/{}/assignments/{}/submissions/{}",
+            course_id, assignment_id, user_id
+        ),
+    )
+}
+
+/// Update a user's submission for an assignment, such as to comment on it as a peer reviewer or to grade it.
+///
+/// Send with [`Endpoint::send_json`] and a [`SubmissionUpdate`].
+#[inline]
+pub fn update_submission<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    assignment_id: Id,
+    user_id: Id,
+) -> Endpoint<'_, Conn, Submission> {
+    Endpoint::new(
+        client,
+        Method::PUT,
+        format!(
+            "/api/v1/courses/{}/assignments/{}/submissions/{}",
+            course_id, assignment_id, user_id
+        ),
+    )
+}
+
+/// Start uploading a file to attach to a comment on a user's submission.
+///
+/// Send with [`Endpoint::send_json`] and a [`NewFileUpload`](super::file::NewFileUpload),
+/// then upload the file's contents as described by [`FileUpload`] and attach it with [`SubmissionUpdate::comment_with_files`].
+#[inline]
+pub fn upload_comment_file<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    assignment_id: Id,
+    user_id: Id,
+) -> Endpoint<'_, Conn, FileUpload> {
+    Endpoint::new(
+        client,
+        Method::POST,
+        format!(
+            "/api/v1/courses/{}/assignments/{}/submissions/{}/comments/files",
+            course_id, assignment_id, user_id
+        ),
+    )
+}
+
+/// Grade many students' submissions for an assignment at once, which requires permission to grade it.
+///
+/// Send with [`Endpoint::send_json`] and a [`BulkGradeUpdate`], then poll the returned [`Progress`]
+/// with [`get_progress`](super::progress::get_progress) until the grades are applied.
+#[inline]
+pub fn update_grades<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    assignment_id: Id,
+) -> Endpoint<'_, Conn, Progress> {
+    Endpoint::new(
+        client,
+        Method::POST,
+        format!(
+            "/api/v1/courses/{}/assignments/{}/submissions/update_grades",
+            course_id, assignment_id
+        ),
+    )
+}
@@ -0,0 +1,171 @@
+//! Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/users.html).
+
+use super::Endpoint;
+use crate::{
+    resource::{
+        enrollment::EnrollmentType, ActivityStreamItem, Course, CourseNickname, Enrollment,
+        Profile, TodoItem, UpcomingEvent, User,
+    },
+    Client, Id,
+};
+use hyper::Method;
+use serde::{Deserialize, Serialize};
+
+/// Filters for [`list_course_users`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RosterFilter {
+    /// Defaults to every enrollment type.
+    pub enrollment_types: Vec<EnrollmentType>,
+    /// Matches part of a user's name, login ID or email, and must be at least two characters long.
+    pub search_term: Option<String>,
+}
+
+/// A nickname to give a course in place of its own name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NewCourseNickname {
+    /// At most 59 characters long.
+    pub nickname: String,
+}
+
+/// List the users enrolled in a course.
+///
+/// Use [`Endpoint::extend_include`] with `avatar_url`, `enrollments` and `email` to include those fields on each user.
+#[inline]
+pub fn list_course_users<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    filter: RosterFilter,
+) -> Endpoint<'_, Conn, Vec<User>> {
+    let endpoint = Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/users", course_id),
+    )
+    .extend_query(
+        filter
+            .enrollment_types
+            .iter()
+            .map(|enrollment_type| ("enrollment_type[]", enrollment_type.as_str())),
+    );
+
+    match filter.search_term {
+        Some(search_term) => endpoint.query("search_term", search_term),
+        None => endpoint,
+    }
+}
+
+/// Get a single user in a course.
+#[inline]
+pub fn get_course_user<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+    user_id: Id,
+) -> Endpoint<'_, Conn, User> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/courses/{}/users/{}", course_id, user_id),
+    )
+}
+
+/// List the students the current user observes, such as a parent's children.
+///
+/// Use [`Endpoint::include`] with `avatar_url` to include each student's avatar.
+#[inline]
+pub fn list_observees<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, Vec<User>> {
+    Endpoint::new(client, Method::GET, "/api/v1/users/self/observees")
+}
+
+/// List the courses a user is enrolled in, which requires being that user or observing them.
+///
+/// Use [`Endpoint::include`] with `total_scores` to include the user's grades in each course's enrollments.
+#[inline]
+pub fn list_user_courses<Conn>(
+    client: &Client<Conn>,
+    user_id: Id,
+) -> Endpoint<'_, Conn, Vec<Course>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/users/{}/courses", user_id),
+    )
+}
+
+/// List a user's enrollments along with their grades, which requires being that user or observing them.
+#[inline]
+pub fn list_user_enrollments<Conn>(
+    client: &Client<Conn>,
+    user_id: Id,
+) -> Endpoint<'_, Conn, Vec<Enrollment>> {
+    Endpoint::new(
+        client,
+        Method::GET,
+        format!("/api/v1/users/{}/enrollments", user_id),
+    )
+}
+
+/// Get the current user's profile.
+#[inline]
+pub fn get_own_profile<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, Profile> {
+    Endpoint::new(client, Method::GET, "/api/v1/users/self/profile")
+}
+
+/// List the current user's recent activity across all of their courses and groups, most recent first.
+#[inline]
+pub fn list_activity_stream<Conn>(
+    client: &Client<Conn>,
+    only_active_courses: bool,
+) -> Endpoint<'_, Conn, Vec<ActivityStreamItem>> {
+    Endpoint::new(client, Method::GET, "/api/v1/users/self/activity_stream")
+        .query("only_active_courses", only_active_courses.to_string())
+}
+
+/// List the assignments the current user needs to submit or grade.
+#[inline]
+pub fn list_todo_items<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, Vec<TodoItem>> {
+    Endpoint::new(client, Method::GET, "/api/v1/users/self/todo").include("ungraded_quizzes")
+}
+
+/// List the current user's upcoming calendar events and assignments.
+///
+/// Canvas only responds with a single page of events.
+#[inline]
+pub fn list_upcoming_events<Conn>(client: &Client<Conn>) -> Endpoint<'_, Conn, Vec<UpcomingEvent>> {
+    Endpoint::new(client, Method::GET, "/api/v1/users/self/upcoming_events")
+}
+
+/// List the nicknames the current user has given their courses.
+#[inline]
+pub fn list_course_nicknames<Conn>(
+    client: &Client<Conn>,
+) -> Endpoint<'_, Conn, Vec<CourseNickname>> {
+    Endpoint::new(client, Method::GET, "/api/v1/users/self/course_nicknames")
+}
+
+/// Set the current user's nickname for a course, replacing any existing one.
+///
+/// Send with [`Endpoint::send_json`] and a [`NewCourseNickname`].
+#[inline]
+pub fn set_course_nickname<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, CourseNickname> {
+    Endpoint::new(
+        client,
+        Method::PUT,
+        format!("/api/v1/users/self/course_nicknames/{}", course_id),
+    )
+}
+
+/// Remove the current user's nickname for a course, restoring its own name.
+#[inline]
+pub fn remove_course_nickname<Conn>(
+    client: &Client<Conn>,
+    course_id: Id,
+) -> Endpoint<'_, Conn, CourseNickname> {
+    Endpoint::new(
+        client,
+        Method::DELETE,
+        format!("/api/v1/users/self/course_nicknames/{}", course_id),
+    )
+}
@@ -4,9 +4,15 @@
 
 #[cfg(feature = "client")]
 pub mod client;
+pub mod context;
+#[cfg(feature = "client")]
+pub mod endpoint;
 pub mod id;
 pub mod resource;
 
 #[cfg(feature = "client")]
 pub use client::{Auth, Client};
+pub use context::Context;
+#[cfg(feature = "client")]
+pub use endpoint::Endpoint;
 pub use id::Id;
@@ -0,0 +1,78 @@
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A Canvas Account, which owns courses and enrollment terms and may be nested in a parent account.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/accounts.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Account {
+    pub id: Id,
+    pub name: String,
+    pub uuid: String,
+
+    pub parent_account_id: Option<Id>,
+    pub root_account_id: Option<Id>, // absent on root accounts
+
+    #[serde(default)]
+    pub default_time_zone: Option<String>,
+    #[serde(default)]
+    pub workflow_state: Option<String>,
+}
+
+/// A Canvas Enrollment Term, such as a semester, which determines when its courses are active.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/enrollment_terms.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnrollmentTerm {
+    pub id: Id,
+    pub name: String,
+    #[serde(default)]
+    pub sis_term_id: Option<String>,
+
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub workflow_state: TermWorkflowState,
+    pub grading_period_group_id: Option<Id>,
+
+    #[serde(default)]
+    pub overrides: HashMap<String, TermOverride>, // keyed by enrollment type, such as `StudentEnrollment`
+}
+
+/// Dates which override an [`EnrollmentTerm`]'s for a single enrollment type.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TermOverride {
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TermWorkflowState {
+    Active,
+    Deleted,
+}
+
+/// The response to listing [`EnrollmentTerm`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnrollmentTerms {
+    pub enrollment_terms: Vec<EnrollmentTerm>,
+}
@@ -0,0 +1,41 @@
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A global notification shown to users of a Canvas account.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/account_notifications.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AccountNotification {
+    pub id: Id,
+
+    pub subject: String,
+    pub message: String,
+    pub icon: NotificationIcon,
+
+    pub start_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub role_ids: Vec<Id>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationIcon {
+    Warning,
+    Information,
+    Question,
+    Error,
+    Calendar,
+}
@@ -0,0 +1,273 @@
+use super::{submission::SubmissionWorkflowState, Assignment, Quiz};
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// An item in a Canvas user's activity stream, which is tagged by the kind of activity it records.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/users.html#method.users.activity_stream).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ActivityStreamItem {
+    DiscussionTopic(DiscussionTopicActivity),
+    Announcement(AnnouncementActivity),
+    Conversation(ConversationActivity),
+    Message(MessageActivity),
+    Submission(SubmissionActivity),
+    #[serde(rename = "WebConference")]
+    Conference(ConferenceActivity),
+    Collaboration(CollaborationActivity),
+    AssessmentRequest(AssessmentRequestActivity),
+    /// An activity of a kind this crate doesn't know about, such as one Canvas has since added.
+    #[serde(other)]
+    Other,
+}
+
+impl ActivityStreamItem {
+    /// The fields shared by every known kind of activity.
+    pub fn activity(&self) -> Option<&Activity> {
+        match self {
+            Self::DiscussionTopic(item) => Some(&item.activity),
+            Self::Announcement(item) => Some(&item.activity),
+            Self::Conversation(item) => Some(&item.activity),
+            Self::Message(item) => Some(&item.activity),
+            Self::Submission(item) => Some(&item.activity),
+            Self::Conference(item) => Some(&item.activity),
+            Self::Collaboration(item) => Some(&item.activity),
+            Self::AssessmentRequest(item) => Some(&item.activity),
+            Self::Other => None,
+        }
+    }
+}
+
+/// The fields shared by every [`ActivityStreamItem`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Activity {
+    pub id: Id,
+
+    pub title: Option<String>,
+    pub message: Option<String>,
+    pub html_url: Option<String>,
+    #[serde(default)]
+    pub read_state: bool,
+
+    pub context_type: Option<String>,
+    #[serde(default)]
+    pub course_id: Option<Id>,
+    #[serde(default)]
+    pub group_id: Option<Id>,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DiscussionTopicActivity {
+    #[serde(flatten)]
+    pub activity: Activity,
+
+    pub discussion_topic_id: Id,
+    pub total_root_discussion_entries: u32,
+    pub require_initial_post: bool,
+    pub user_has_posted: Option<bool>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AnnouncementActivity {
+    #[serde(flatten)]
+    pub activity: Activity,
+
+    pub announcement_id: Id,
+    pub total_root_discussion_entries: u32,
+    pub require_initial_post: bool,
+    pub user_has_posted: Option<bool>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConversationActivity {
+    #[serde(flatten)]
+    pub activity: Activity,
+
+    pub conversation_id: Id,
+    pub private: bool,
+    pub participant_count: u32,
+}
+
+/// A notification Canvas sent to the user, such as a grade change.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MessageActivity {
+    #[serde(flatten)]
+    pub activity: Activity,
+
+    pub message_id: Option<Id>,
+    pub notification_category: Option<String>,
+}
+
+/// A submission which has been graded or commented on.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionActivity {
+    #[serde(flatten)]
+    pub activity: Activity,
+
+    pub assignment_id: Id,
+    pub user_id: Id,
+    pub workflow_state: SubmissionWorkflowState,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub graded_at: Option<DateTime<Utc>>,
+    pub grade: Option<String>,
+    pub score: Option<f64>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConferenceActivity {
+    #[serde(flatten)]
+    pub activity: Activity,
+
+    pub web_conference_id: Id,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CollaborationActivity {
+    #[serde(flatten)]
+    pub activity: Activity,
+
+    pub collaboration_id: Id,
+}
+
+/// A request for the user to peer review a submission.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AssessmentRequestActivity {
+    #[serde(flatten)]
+    pub activity: Activity,
+
+    pub assessment_request_id: Id,
+}
+
+/// An item on a Canvas user's to-do list, which is an assignment they need to either submit or grade.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/users.html#method.users.todo_items).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TodoItem {
+    #[serde(rename = "type")]
+    pub todo_type: TodoType,
+
+    #[serde(default)]
+    pub assignment: Option<Assignment>,
+    #[serde(default)]
+    pub quiz: Option<Quiz>, // present on ungraded quizzes
+    #[serde(default)]
+    pub needs_grading_count: Option<u32>, // present on items of type `grading`
+
+    pub context_type: String,
+    #[serde(default)]
+    pub course_id: Option<Id>,
+    #[serde(default)]
+    pub group_id: Option<Id>,
+
+    pub html_url: String,
+    pub ignore: String, // a URL which removes the item from the to-do list
+    pub ignore_permanently: String,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TodoType {
+    Submitting,
+    Grading,
+}
+
+#[cfg(test)]
+#[test]
+fn deserialize_activity_stream_item() {
+    let item: ActivityStreamItem = serde_json::from_str(
+        r#"{
+            "id": 1234,
+            "type": "Conversation",
+            "title": null,
+            "message": "Are we still meeting tomorrow?",
+            "html_url": "https://canvas.example.com/conversations/5",
+            "read_state": false,
+            "context_type": null,
+            "created_at": "2022-03-01T12:00:00Z",
+            "updated_at": "2022-03-01T12:00:00Z",
+            "conversation_id": 5,
+            "private": true,
+            "participant_count": 2
+        }"#,
+    )
+    .unwrap();
+
+    assert!(matches!(
+        &item,
+        ActivityStreamItem::Conversation(conversation) if conversation.participant_count == 2
+    ));
+    let activity = item.activity().unwrap();
+    assert_eq!(activity.id, Id::new(1234));
+    assert!(!activity.read_state);
+}
+
+#[cfg(test)]
+#[test]
+fn deserialize_unknown_activity_stream_item() {
+    let item: ActivityStreamItem = serde_json::from_str(
+        r#"{
+            "id": 1235,
+            "type": "HologramLecture",
+            "title": "Week 5",
+            "created_at": "2022-03-01T12:00:00Z",
+            "updated_at": "2022-03-01T12:00:00Z"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(item, ActivityStreamItem::Other);
+    assert!(item.activity().is_none());
+}
@@ -0,0 +1,111 @@
+use crate::Id;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A student's page views and participations in a course.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/analytics.html#method.analytics_api.student_in_course_participation).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StudentActivity {
+    pub page_views: HashMap<DateTime<Utc>, u32>, // keyed by the hour the pages were viewed in
+    pub participations: Vec<Participation>,
+}
+
+/// An action which counts as participating in a course, such as submitting an assignment or posting to a discussion.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Participation {
+    pub created_at: DateTime<Utc>,
+    pub url: String,
+}
+
+/// How a student's score on an assignment compares to the rest of the course.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/analytics.html#method.analytics_api.student_in_course_assignments).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssignmentStatistics {
+    pub assignment_id: Id,
+    pub title: String,
+    pub points_possible: Option<f64>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub unlock_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub muted: bool,
+
+    // absent until enough students have been graded
+    pub min_score: Option<f64>,
+    pub max_score: Option<f64>,
+    pub median: Option<f64>,
+    pub first_quartile: Option<f64>,
+    pub third_quartile: Option<f64>,
+
+    #[serde(default)]
+    pub module_ids: Vec<Id>,
+    pub submission: Option<SubmissionStatistics>,
+}
+
+impl AssignmentStatistics {
+    /// Which quarter of the course the student's score falls into, from 1 for the lowest scores to 4 for the highest.
+    pub fn quartile(&self) -> Option<u8> {
+        let score = self.submission.as_ref()?.score?;
+
+        Some(if score < self.first_quartile? {
+            1
+        } else if score < self.median? {
+            2
+        } else if score < self.third_quartile? {
+            3
+        } else {
+            4
+        })
+    }
+}
+
+/// The student's own submission for an [`AssignmentStatistics`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubmissionStatistics {
+    pub submitted_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub posted_at: Option<DateTime<Utc>>,
+    pub score: Option<f64>,
+}
+
+/// The messages sent between a student and their instructors in a course, keyed by the day they were sent.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/analytics.html#method.analytics_api.student_in_course_messaging).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StudentCommunication(pub HashMap<NaiveDate, MessageCounts>);
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageCounts {
+    #[serde(default)]
+    pub instructor_messages: u32,
+    #[serde(default)]
+    pub student_messages: u32,
+}
@@ -1,4 +1,7 @@
-use super::submission::SubmissionType;
+use super::{
+    rubric::{RubricCriterion, RubricSettings},
+    submission::SubmissionType,
+};
 use crate::Id;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -18,6 +21,8 @@ pub struct Assignment {
     pub description: Option<String>,
 
     pub course_id: Id,
+    #[serde(default)]
+    pub assignment_group_id: Option<Id>, // absent on assignments cached before it was added
     pub html_url: String,
 
     pub created_at: DateTime<Utc>,
@@ -26,12 +31,28 @@ pub struct Assignment {
     pub lock_at: Option<DateTime<Utc>>,
     pub unlock_at: Option<DateTime<Utc>>,
 
+    pub points_possible: Option<f64>,
     pub submission_types: Vec<SubmissionType>,
     pub has_submitted_submissions: bool,
 
+    #[serde(default)]
+    pub peer_reviews: bool,
+    #[serde(default)]
+    pub peer_reviews_assign_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub quiz_id: Option<Id>, // present if the submission type is `online_quiz`
+    #[serde(default)]
+    pub external_tool_tag_attributes: Option<ExternalToolTagAttributes>, // present if the submission type is `external_tool`
+
     #[serde(default)]
     pub score_statistics: Option<ScoreStatistics>, // included on include[]=score_statistics,submission
 
+    #[serde(default)]
+    pub rubric: Option<Vec<RubricCriterion>>, // present if the assignment has a rubric
+    #[serde(default)]
+    pub rubric_settings: Option<RubricSettings>,
+
     pub locked_for_user: bool,
     #[serde(default)]
     pub lock_info: Option<LockInfo>,
@@ -39,6 +60,32 @@ pub struct Assignment {
     pub grading_type: GradingType,
 }
 
+impl Assignment {
+    /// Whether the assignment is completed in an external tool, which must be opened with a sessionless launch.
+    pub fn is_external_tool(&self) -> bool {
+        self.submission_types.iter().any(|submission_type| {
+            matches!(
+                submission_type,
+                SubmissionType::ExternalTool | SubmissionType::BasicLtiLaunch
+            )
+        })
+    }
+}
+
+/// The external tool an assignment is completed in.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ExternalToolTagAttributes {
+    pub url: String,
+    #[serde(default)]
+    pub new_tab: bool,
+    #[serde(default)]
+    pub content_id: Option<Id>, // the ID of the external tool
+}
+
 #[cfg_attr(
     feature = "typescript-definitions",
     derive(typescript_definitions::TypeScriptify)
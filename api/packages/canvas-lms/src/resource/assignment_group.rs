@@ -0,0 +1,39 @@
+use super::Assignment;
+use crate::Id;
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Assignment Group, which is a weighted category of assignments within a course.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/assignment_groups.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssignmentGroup {
+    pub id: Id,
+
+    pub name: String,
+    pub position: u32,
+    pub group_weight: Option<f64>, // only counts towards the course grade if the course weights its groups
+    #[serde(default)]
+    pub rules: AssignmentGroupRules,
+
+    #[serde(default)]
+    pub assignments: Vec<Assignment>, // present on include[]=assignments
+}
+
+/// The rules for which assignments are dropped when calculating an [`AssignmentGroup`]'s grade.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AssignmentGroupRules {
+    #[serde(default)]
+    pub drop_lowest: Option<u32>,
+    #[serde(default)]
+    pub drop_highest: Option<u32>,
+    #[serde(default)]
+    pub never_drop: Vec<Id>, // assignment IDs
+}
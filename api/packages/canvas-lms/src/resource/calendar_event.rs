@@ -0,0 +1,202 @@
+use super::Assignment;
+use crate::{Context, Id};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Calendar Event.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/calendar_events.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarEvent {
+    pub id: Id,
+
+    pub title: String,
+    pub description: Option<String>,
+    pub location_name: Option<String>,
+    pub location_address: Option<String>,
+    pub html_url: String,
+
+    pub context_code: Context,
+    #[serde(default)]
+    pub effective_context_code: Option<String>, // a comma-separated list of context codes
+
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+    pub all_day: bool,
+    pub all_day_date: Option<NaiveDate>,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub workflow_state: CalendarEventWorkflowState,
+    #[serde(default)]
+    pub hidden: bool,
+
+    pub parent_event_id: Option<Id>,
+    #[serde(default)]
+    pub child_events_count: u32,
+
+    // present on events which are appointment group slots
+    #[serde(default)]
+    pub appointment_group_id: Option<Id>,
+    #[serde(default)]
+    pub reserve_url: Option<String>,
+    #[serde(default)]
+    pub reserved: Option<bool>,
+    #[serde(default)]
+    pub available_slots: Option<u32>,
+    #[serde(default)]
+    pub participants_per_appointment: Option<u32>,
+    #[serde(default)]
+    pub own_reservation: Option<bool>,
+}
+
+/// An assignment as it appears on the Canvas calendar.
+///
+/// Canvas returns these instead of [`CalendarEvent`]s when listing events of type `assignment`.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssignmentEvent {
+    pub id: String, // of the form `assignment_<id>`
+
+    pub title: String,
+    pub description: Option<String>,
+    pub html_url: String,
+    pub context_code: Context,
+
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+    pub all_day: bool,
+    pub all_day_date: Option<NaiveDate>,
+
+    pub workflow_state: String,
+    pub assignment: Assignment,
+}
+
+/// An item from a Canvas user's upcoming events, which is tagged as either an event or an assignment.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/users.html#method.users.upcoming_events).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum UpcomingEvent {
+    Event(Box<CalendarEvent>),
+    Assignment(Box<AssignmentEvent>),
+}
+
+impl UpcomingEvent {
+    pub fn start_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Event(event) => event.start_at,
+            Self::Assignment(event) => event.start_at,
+        }
+    }
+}
+
+/// A group of appointment slots, such as office hours, which users can sign up for.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/appointment_groups.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppointmentGroup {
+    pub id: Id,
+
+    pub title: String,
+    pub description: Option<String>,
+    pub location_name: Option<String>,
+    pub location_address: Option<String>,
+    pub html_url: String,
+
+    pub context_codes: Vec<Context>,
+    #[serde(default)]
+    pub sub_context_codes: Vec<String>, // course sections, which are not supported by [`Context`]
+
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub workflow_state: AppointmentGroupWorkflowState,
+
+    pub requiring_action: bool,
+    pub appointments_count: u32,
+    pub participant_count: u32,
+    pub participants_per_appointment: Option<u32>,
+    pub min_appointments_per_participant: Option<u32>,
+    pub max_appointments_per_participant: Option<u32>,
+    pub participant_type: ParticipantType,
+    pub participant_visibility: ParticipantVisibility,
+
+    #[serde(default)]
+    pub reserved_times: Vec<ReservedTime>,
+    #[serde(default)]
+    pub appointments: Vec<CalendarEvent>, // present on include[]=appointments
+}
+
+/// A time slot in an [`AppointmentGroup`] reserved by the current user.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReservedTime {
+    pub id: Id,
+    pub start_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalendarEventWorkflowState {
+    Active,
+    Locked,
+    Deleted,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppointmentGroupWorkflowState {
+    Pending,
+    Active,
+    Deleted,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ParticipantType {
+    User,
+    Group,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParticipantVisibility {
+    Private,
+    Protected,
+}
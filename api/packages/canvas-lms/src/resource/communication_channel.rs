@@ -0,0 +1,82 @@
+use crate::Id;
+use serde::{Deserialize, Serialize};
+
+/// A means by which Canvas can notify a user, such as an email address.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/communication_channels.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CommunicationChannel {
+    pub id: Id,
+    pub user_id: Id,
+    pub address: String,
+    #[serde(rename = "type")]
+    pub channel_type: ChannelType,
+    pub position: u32, // lower positions are preferred
+    pub workflow_state: ChannelWorkflowState,
+}
+
+/// How often a [`CommunicationChannel`] is notified of a kind of event.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/notification_preferences.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NotificationPreference {
+    pub notification: String, // such as `new_announcement`
+    pub category: String,     // such as `announcement`
+    pub frequency: NotificationFrequency,
+    #[serde(default)]
+    pub href: Option<String>,
+}
+
+/// The notification preferences of a [`CommunicationChannel`], as Canvas responds with them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NotificationPreferences {
+    pub notification_preferences: Vec<NotificationPreference>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelType {
+    Email,
+    Sms,
+    Push,
+    Slack,
+    #[serde(rename = "personal_mms")]
+    PersonalMms,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelWorkflowState {
+    Active,
+    Unconfirmed,
+    Retired,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationFrequency {
+    Immediately,
+    Daily,
+    Weekly,
+    Never,
+}
@@ -0,0 +1,76 @@
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Content Export, which archives a course's content for download.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/content_exports.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ContentExport {
+    pub id: Id,
+    pub user_id: Id,
+    pub created_at: DateTime<Utc>,
+
+    pub export_type: ExportType,
+    pub workflow_state: ExportWorkflowState,
+
+    #[serde(default)]
+    pub progress_url: Option<String>,
+    #[serde(default)]
+    pub attachment: Option<ExportAttachment>, // present once the export is `exported`
+}
+
+impl ContentExport {
+    /// The ID of the [`Progress`](super::Progress) tracking the export, taken from its progress URL.
+    pub fn progress_id(&self) -> Option<Id> {
+        self.progress_url
+            .as_deref()?
+            .rsplit('/')
+            .next()?
+            .parse()
+            .ok()
+    }
+}
+
+/// The archive of a finished [`ContentExport`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ExportAttachment {
+    pub url: String, // a download URL, which is valid without authentication
+    #[serde(default)]
+    pub filename: Option<String>,
+    #[serde(default)]
+    pub size: Option<u64>, // in bytes
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportType {
+    CommonCartridge, // an IMS Common Cartridge which can be imported into another course
+    Zip,             // the course's files
+    Qti,             // the course's quizzes
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportWorkflowState {
+    Created,
+    Exporting,
+    Exported,
+    Failed,
+}
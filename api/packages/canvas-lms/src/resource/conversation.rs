@@ -0,0 +1,110 @@
+use super::File;
+use crate::{Context, Id};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Conversation, which is a thread of messages in a user's inbox.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/conversations.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Conversation {
+    pub id: Id,
+
+    pub subject: Option<String>,
+    pub workflow_state: ConversationWorkflowState,
+    pub last_message: Option<String>,
+    pub last_message_at: Option<DateTime<Utc>>,
+    pub message_count: u32,
+
+    pub subscribed: bool,
+    pub private: bool,
+    pub starred: bool,
+    #[serde(default)]
+    pub properties: Vec<ConversationProperty>,
+
+    #[serde(default)]
+    pub audience: Vec<Id>, // the participants other than the current user
+    pub participants: Vec<ConversationParticipant>,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+
+    #[serde(default)]
+    pub context_code: Option<Context>,
+    #[serde(default)]
+    pub context_name: Option<String>,
+
+    #[serde(default)]
+    pub messages: Vec<ConversationMessage>, // present when getting a single conversation
+}
+
+impl Conversation {
+    pub fn is_unread(&self) -> bool {
+        self.workflow_state == ConversationWorkflowState::Unread
+    }
+}
+
+/// A single message in a [`Conversation`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConversationMessage {
+    pub id: Id,
+    pub author_id: Id,
+    pub created_at: DateTime<Utc>,
+    pub body: String,
+    pub generated: bool, // messages generated by Canvas, such as when a participant is added
+
+    #[serde(default)]
+    pub attachments: Vec<File>,
+    #[serde(default)]
+    pub forwarded_messages: Vec<ConversationMessage>,
+    #[serde(default)]
+    pub participating_user_ids: Vec<Id>,
+}
+
+/// A user taking part in a [`Conversation`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConversationParticipant {
+    pub id: Id,
+    pub name: String,
+    #[serde(default)]
+    pub full_name: Option<String>,
+    #[serde(default)]
+    pub avatar_url: Option<String>, // present on include[]=participant_avatars
+    #[serde(default)]
+    pub pronouns: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationWorkflowState {
+    Read,
+    Unread,
+    Archived,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationProperty {
+    LastAuthor,
+    Attachments,
+    MediaObjects,
+}
@@ -14,7 +14,9 @@ use serde::{Deserialize, Serialize};
 pub struct Course {
     pub id: Id,
 
-    pub name: String,
+    pub name: String, // the user's nickname for the course, if they've set one
+    #[serde(default)]
+    pub original_name: Option<String>, // present if the user has set a nickname
     pub course_code: String,
     pub workflow_state: CourseWorkflowState,
 
@@ -55,6 +57,20 @@ pub struct Course {
     pub is_favorite: Option<bool>, // present on include[]=favorites
 }
 
+/// A name the user has given a course in place of its own.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/users.html#CourseNickname).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CourseNickname {
+    pub course_id: Id,
+    pub name: String,             // the course's own name
+    pub nickname: Option<String>, // absent once the nickname is removed
+}
+
 #[cfg_attr(
     feature = "typescript-definitions",
     derive(typescript_definitions::TypeScriptify)
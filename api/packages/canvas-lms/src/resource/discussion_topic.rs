@@ -0,0 +1,182 @@
+use super::{assignment::LockInfo, file::File, user::UserDisplay};
+use crate::{Context, Id};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A Canvas Discussion Topic.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/discussion_topics.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiscussionTopic {
+    pub id: Id,
+
+    pub title: String,
+    pub message: Option<String>,
+    pub html_url: String,
+
+    #[serde(default)]
+    pub context_code: Option<Context>, // present when listing announcements
+
+    pub posted_at: Option<DateTime<Utc>>,
+    pub last_reply_at: Option<DateTime<Utc>>,
+    pub delayed_post_at: Option<DateTime<Utc>>,
+    pub lock_at: Option<DateTime<Utc>>,
+
+    pub discussion_type: DiscussionType,
+    pub require_initial_post: Option<bool>,
+    pub user_can_see_posts: bool,
+    pub discussion_subentry_count: u32,
+
+    pub read_state: ReadState,
+    pub unread_count: u32,
+    pub subscribed: bool,
+
+    pub assignment_id: Option<Id>, // set if the topic is graded
+
+    pub published: bool,
+    pub pinned: bool,
+    pub locked: bool,
+    pub locked_for_user: bool,
+    #[serde(default)]
+    pub lock_info: Option<LockInfo>,
+    #[serde(default)]
+    pub lock_explanation: Option<String>,
+
+    pub user_name: Option<String>,
+    #[serde(default)]
+    pub author: Option<UserDisplay>,
+
+    #[serde(default)]
+    pub attachments: Vec<File>,
+
+    #[serde(default)]
+    pub topic_children: Vec<Id>,
+    pub root_topic_id: Option<Id>,
+    pub group_category_id: Option<Id>,
+
+    #[serde(default)]
+    pub allow_rating: bool,
+    #[serde(default)]
+    pub only_graders_can_rate: bool,
+    #[serde(default)]
+    pub sort_by_rating: bool,
+}
+
+/// An entry (or reply to an entry) in a [`DiscussionTopic`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiscussionEntry {
+    pub id: Id,
+
+    pub user_id: Option<Id>,
+    #[serde(default)]
+    pub editor_id: Option<Id>,
+    #[serde(default)]
+    pub user_name: Option<String>,
+
+    #[serde(default)]
+    pub message: Option<String>, // absent on deleted entries
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub attachments: Vec<File>,
+
+    pub read_state: ReadState,
+    pub forced_read_state: bool,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+
+    #[serde(default)]
+    pub parent_id: Option<Id>,
+    #[serde(default)]
+    pub recent_replies: Vec<DiscussionEntry>, // present when listing top-level entries
+    #[serde(default)]
+    pub has_more_replies: bool,
+
+    #[serde(default)]
+    pub rating_count: Option<u32>,
+    #[serde(default)]
+    pub rating_sum: Option<u32>,
+}
+
+/// The full, cached representation of a [`DiscussionTopic`]'s entries.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/discussion_topics.html#method.discussion_topics_api.view).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FullTopicView {
+    pub participants: Vec<UserDisplay>,
+    pub unread_entries: Vec<Id>,
+    pub forced_entries: Vec<Id>,
+    #[serde(default)]
+    pub entry_ratings: HashMap<Id, u32>,
+
+    pub view: Vec<ThreadedEntry>,
+    #[serde(default)]
+    pub new_entries: Vec<ThreadedEntry>, // entries created since the view was cached by Canvas
+}
+
+/// A discussion entry as it appears in a [`FullTopicView`], with its replies nested inside it.
+///
+/// Note that these entries do not carry their read state; consult [`FullTopicView::unread_entries`] instead.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThreadedEntry {
+    pub id: Id,
+
+    #[serde(default)]
+    pub user_id: Option<Id>,
+    #[serde(default)]
+    pub editor_id: Option<Id>,
+    #[serde(default)]
+    pub parent_id: Option<Id>,
+
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub deleted: bool,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+
+    #[serde(default)]
+    pub replies: Vec<ThreadedEntry>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscussionType {
+    SideComment,
+    Threaded,
+    NotThreaded,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadState {
+    Read,
+    Unread,
+}
@@ -55,6 +55,8 @@ pub struct InlineEnrollment {
     pub associated_user_id: Option<Id>, // set if we are enrolled as an observer
     pub role: EnrollmentRole,
     pub role_id: Id,
+    #[serde(default)]
+    pub course_section_id: Option<Id>, // present on enrollments embedded in users
 
     #[serde(default)]
     pub computed_current_score: Option<f64>,
@@ -76,6 +78,12 @@ pub enum EnrollmentState {
     Active,
     Invited,
     Inactive,
+    Completed,
+    Rejected,
+    CreationPending,
+    Deleted,
+    #[serde(other)]
+    Other,
 }
 
 #[cfg_attr(
@@ -85,13 +93,32 @@ pub enum EnrollmentState {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EnrollmentType {
+    #[serde(alias = "StudentEnrollment")]
     Student,
+    #[serde(alias = "TeacherEnrollment")]
     Teacher,
+    #[serde(alias = "TaEnrollment")]
     Ta,
+    #[serde(alias = "DesignerEnrollment")]
     Designer,
+    #[serde(alias = "ObserverEnrollment")]
     Observer,
 }
 
+impl EnrollmentType {
+    /// The enrollment type as Canvas expects it in query parameters such as `enrollment_type[]`.
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Student => "student",
+            Self::Teacher => "teacher",
+            Self::Ta => "ta",
+            Self::Designer => "designer",
+            Self::Observer => "observer",
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "typescript-definitions",
     derive(typescript_definitions::TypeScriptify)
@@ -104,6 +131,9 @@ pub enum EnrollmentRole {
     TaEnrollment,
     DesignerEnrollment,
     ObserverEnrollment,
+    /// A custom role defined by the account, such as a grader, which `role_id` identifies.
+    #[serde(other)]
+    Other,
 }
 
 #[cfg_attr(
@@ -125,3 +155,28 @@ pub struct Grade {
     #[serde(default)]
     pub current_points: Option<f64>, // present when include[]=current_points
 }
+
+#[cfg(test)]
+#[test]
+fn deserialize_enrollment_with_custom_role() {
+    let enrollment: InlineEnrollment = serde_json::from_str(
+        r#"{
+            "enrollment_state": "completed",
+            "type": "TaEnrollment",
+            "user_id": 12,
+            "associated_user_id": null,
+            "role": "Grader",
+            "role_id": 34,
+            "course_section_id": 56
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(enrollment.enrollment_state, EnrollmentState::Completed);
+    assert_eq!(enrollment.enrollment_type, EnrollmentType::Ta);
+    assert_eq!(enrollment.role, EnrollmentRole::Other);
+    assert_eq!(enrollment.role_id, Id::new(34));
+
+    let state: EnrollmentState = serde_json::from_str(r#""archived""#).unwrap();
+    assert_eq!(state, EnrollmentState::Other);
+}
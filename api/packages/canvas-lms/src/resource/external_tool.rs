@@ -0,0 +1,86 @@
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Canvas External Tool, which is an LTI tool installed in a course or account.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/external_tools.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ExternalTool {
+    pub id: Id,
+
+    pub name: String,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub domain: Option<String>,
+    #[serde(default)]
+    pub icon_url: Option<String>,
+    pub privacy_level: PrivacyLevel,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub workflow_state: Option<String>,
+
+    // placements, which are absent if the tool doesn't appear there
+    #[serde(default)]
+    pub course_navigation: Option<ToolPlacement>,
+    #[serde(default)]
+    pub assignment_selection: Option<ToolPlacement>,
+    #[serde(default)]
+    pub homework_submission: Option<ToolPlacement>,
+    #[serde(default)]
+    pub editor_button: Option<ToolPlacement>,
+}
+
+/// Where and how an [`ExternalTool`] appears in Canvas.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ToolPlacement {
+    #[serde(default)]
+    pub url: Option<String>, // defaults to the tool's URL
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    #[serde(default)]
+    pub visibility: Option<String>, // `admins` or `members`, or absent if everyone can see it
+    #[serde(default)]
+    pub default: Option<String>, // `disabled` if the tool is hidden from course navigation by default
+    #[serde(default)]
+    pub selection_width: Option<u32>,
+    #[serde(default)]
+    pub selection_height: Option<u32>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrivacyLevel {
+    Anonymous,
+    NameOnly,
+    EmailOnly,
+    Public,
+}
+
+/// A one-time URL which launches an [`ExternalTool`] without an existing Canvas session.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SessionlessLaunch {
+    pub id: Id,
+    pub name: String,
+    pub url: String,
+}
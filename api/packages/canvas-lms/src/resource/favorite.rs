@@ -0,0 +1,25 @@
+use crate::Id;
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Favorite, which is a course or group the user has starred.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/favorites.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Favorite {
+    pub context_id: Id,
+    pub context_type: FavoriteContext,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FavoriteContext {
+    Course,
+    Group,
+}
@@ -0,0 +1,228 @@
+use super::assignment::LockInfo;
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// A Canvas File.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/files.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct File {
+    pub id: Id,
+    #[serde(default)]
+    pub folder_id: Option<Id>, // absent on some inline attachments
+
+    pub display_name: String,
+    pub filename: String,
+    #[serde(rename = "content-type")]
+    pub content_type: String,
+    #[serde(default)]
+    pub mime_class: Option<String>,
+    pub size: u64, // in bytes
+
+    pub url: String, // empty if the file is locked for the user
+    #[serde(default)]
+    pub thumbnail_url: Option<String>,
+    #[serde(default)]
+    pub preview_url: Option<String>,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub modified_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub lock_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub unlock_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub locked_for_user: bool,
+    #[serde(default)]
+    pub hidden_for_user: bool,
+    #[serde(default)]
+    pub lock_explanation: Option<String>,
+}
+
+/// A Canvas Folder.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/files.html#Folder).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Folder {
+    pub id: Id,
+    pub parent_folder_id: Option<Id>, // unset for the root folder of a context
+
+    pub name: String,
+    pub full_name: String,
+    pub context_id: Id,
+    pub context_type: String,
+
+    pub files_url: String,
+    pub folders_url: String,
+    pub files_count: u32,
+    pub folders_count: u32,
+    pub position: Option<u32>,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub lock_at: Option<DateTime<Utc>>,
+    pub unlock_at: Option<DateTime<Utc>>,
+
+    pub locked: bool,
+    pub hidden: Option<bool>,
+    pub locked_for_user: bool,
+    pub hidden_for_user: bool,
+    #[serde(default)]
+    pub for_submissions: bool,
+    #[serde(default)]
+    pub can_upload: bool,
+    #[serde(default)]
+    pub lock_info: Option<LockInfo>,
+}
+
+/// Where to upload a file's contents, which is the first step of Canvas's file upload process.
+///
+/// The contents are uploaded by POSTing `upload_params` followed by a `file` field as `multipart/form-data` to `upload_url`,
+/// which responds with the new [`File`].
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/file.file_uploads.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileUpload {
+    pub upload_url: String,
+    pub upload_params: HashMap<String, String>,
+}
+
+/// A [`Folder`] along with all of its descendants.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FolderTree {
+    pub folder: Folder,
+    pub folders: Vec<FolderTree>,
+}
+
+impl FolderTree {
+    /// Assemble a flat list of folders, such as that returned by Canvas's "list all folders" endpoint, into trees.
+    ///
+    /// Any folder whose parent is not in the list becomes the root of a tree.
+    /// Sibling folders are ordered by their position, and then by their name.
+    pub fn build<I: IntoIterator<Item = Folder>>(folders: I) -> Vec<Self> {
+        let folders: Vec<_> = folders.into_iter().collect();
+        let ids: HashSet<_> = folders.iter().map(|folder| folder.id).collect();
+
+        let mut roots = Vec::new();
+        let mut children = HashMap::<Id, Vec<Folder>>::new();
+        for folder in folders {
+            match folder.parent_folder_id {
+                Some(parent) if ids.contains(&parent) => {
+                    children.entry(parent).or_default().push(folder)
+                }
+                _ => roots.push(folder),
+            }
+        }
+
+        Self::attach(roots, &mut children)
+    }
+
+    fn attach(mut folders: Vec<Folder>, children: &mut HashMap<Id, Vec<Folder>>) -> Vec<Self> {
+        folders.sort_by(|a, b| (a.position, &a.name).cmp(&(b.position, &b.name)));
+        folders
+            .into_iter()
+            .map(|folder| {
+                let descendants = children.remove(&folder.id).unwrap_or_default();
+                Self {
+                    folders: Self::attach(descendants, children),
+                    folder,
+                }
+            })
+            .collect()
+    }
+
+    /// Find a folder in this tree by its ID.
+    pub fn find(&self, id: Id) -> Option<&Self> {
+        if self.folder.id == id {
+            Some(self)
+        } else {
+            self.folders.iter().find_map(|child| child.find(id))
+        }
+    }
+
+    /// Iterate over every folder in this tree in depth-first order, starting with the root.
+    pub fn iter(&self) -> impl Iterator<Item = &Folder> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let tree = stack.pop()?;
+            stack.extend(tree.folders.iter().rev());
+            Some(&tree.folder)
+        })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn build_folder_tree() {
+    fn folder(id: u64, parent: Option<u64>, name: &str) -> Folder {
+        Folder {
+            id: Id::new(id),
+            parent_folder_id: parent.map(Id::new),
+            name: name.to_string(),
+            full_name: name.to_string(),
+            context_id: Id::new(1),
+            context_type: "Course".to_string(),
+            files_url: String::new(),
+            folders_url: String::new(),
+            files_count: 0,
+            folders_count: 0,
+            position: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            lock_at: None,
+            unlock_at: None,
+            locked: false,
+            hidden: None,
+            locked_for_user: false,
+            hidden_for_user: false,
+            for_submissions: false,
+            can_upload: false,
+            lock_info: None,
+        }
+    }
+
+    let trees = FolderTree::build([
+        folder(4, Some(2), "lectures"),
+        folder(2, Some(1), "week 1"),
+        folder(1, None, "course files"),
+        folder(3, Some(1), "syllabus"),
+        folder(5, Some(99), "orphan"),
+    ]);
+
+    assert_eq!(trees.len(), 2);
+    let root = trees
+        .iter()
+        .find(|tree| tree.folder.id == Id::new(1))
+        .unwrap();
+    assert_eq!(
+        root.iter().map(|folder| folder.id).collect::<Vec<_>>(),
+        [1, 3, 2, 4].map(Id::new)
+    );
+    assert_eq!(root.find(Id::new(4)).unwrap().folder.name, "lectures");
+    assert!(root.find(Id::new(5)).is_none());
+}
@@ -0,0 +1,134 @@
+use crate::Id;
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Grading Standard, which is a scheme of letter grades or GPA values and the scores they start at.
+///
+/// Courses without a grading standard of their own use [`GradingStandard::default_scheme`].
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/grading_standards.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GradingStandard {
+    pub id: Id,
+    pub title: String,
+    pub context_type: GradingStandardContext,
+    pub context_id: Id,
+
+    #[serde(default)]
+    pub points_based: bool,
+    #[serde(default = "default_scaling_factor")]
+    pub scaling_factor: f64, // the number of points the scheme is out of, if it is points based
+
+    pub grading_scheme: Vec<GradingSchemeEntry>,
+}
+
+impl GradingStandard {
+    /// The letter grade or GPA value for a percentage score, such as `87.5`.
+    ///
+    /// Returns `None` only if the scheme has no entries.
+    pub fn grade_for(&self, percentage: f64) -> Option<&str> {
+        score_to_grade(&self.grading_scheme, percentage)
+    }
+
+    /// The scheme Canvas uses for courses without a grading standard.
+    pub fn default_scheme() -> Vec<GradingSchemeEntry> {
+        [
+            ("A", 0.94),
+            ("A-", 0.9),
+            ("B+", 0.87),
+            ("B", 0.84),
+            ("B-", 0.8),
+            ("C+", 0.77),
+            ("C", 0.74),
+            ("C-", 0.7),
+            ("D+", 0.67),
+            ("D", 0.64),
+            ("D-", 0.61),
+            ("F", 0.0),
+        ]
+        .into_iter()
+        .map(|(name, value)| GradingSchemeEntry {
+            name: name.to_string(),
+            value,
+        })
+        .collect()
+    }
+}
+
+/// A grade in a [`GradingStandard`] and the lowest score which earns it.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GradingSchemeEntry {
+    pub name: String, // a letter grade, or a GPA value such as `3.7`
+    pub value: f64,   // the lower bound as a fraction, such as `0.94`
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GradingStandardContext {
+    Course,
+    Account,
+}
+
+/// Convert a percentage score, such as `87.5`, into a grade using Canvas's cutoff rules.
+///
+/// The grade is that of the highest entry whose lower bound the score meets,
+/// or of the lowest entry if the score is below every lower bound, such as when it is negative.
+/// Scores are compared to a hundredth of a percent so that, say, `94` meets a lower bound of `0.94`
+/// despite floating point error.
+///
+/// Returns `None` only if the scheme has no entries.
+pub fn score_to_grade(scheme: &[GradingSchemeEntry], percentage: f64) -> Option<&str> {
+    // in hundredths of a percent
+    let score = (percentage * 100.0).round() as i64;
+    let lower_bound = |entry: &GradingSchemeEntry| (entry.value * 10_000.0).round() as i64;
+
+    scheme
+        .iter()
+        .filter(|entry| score >= lower_bound(entry))
+        .max_by_key(|entry| lower_bound(entry))
+        .or_else(|| scheme.iter().min_by_key(|entry| lower_bound(entry)))
+        .map(|entry| entry.name.as_str())
+}
+
+fn default_scaling_factor() -> f64 {
+    1.0
+}
+
+#[cfg(test)]
+#[test]
+fn convert_scores_to_grades() {
+    let scheme = GradingStandard::default_scheme();
+
+    assert_eq!(score_to_grade(&scheme, 100.0), Some("A"));
+    assert_eq!(score_to_grade(&scheme, 94.0), Some("A"));
+    assert_eq!(score_to_grade(&scheme, 93.999), Some("A"));
+    assert_eq!(score_to_grade(&scheme, 93.99), Some("A-"));
+    assert_eq!(score_to_grade(&scheme, 87.0), Some("B+"));
+    assert_eq!(score_to_grade(&scheme, 60.5), Some("F"));
+    assert_eq!(score_to_grade(&scheme, -5.0), Some("F"));
+    assert_eq!(score_to_grade(&[], 50.0), None);
+
+    let gpa = [
+        GradingSchemeEntry {
+            name: "2.0".to_string(),
+            value: 0.7,
+        },
+        GradingSchemeEntry {
+            name: "4.0".to_string(),
+            value: 0.9,
+        },
+    ];
+    assert_eq!(score_to_grade(&gpa, 95.0), Some("4.0"));
+    assert_eq!(score_to_grade(&gpa, 80.0), Some("2.0"));
+    assert_eq!(score_to_grade(&gpa, 10.0), Some("2.0"));
+}
@@ -0,0 +1,128 @@
+use crate::{Context, Id};
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Group, such as a group of students working on a project together.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/groups.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Group {
+    pub id: Id,
+    pub name: String,
+    pub description: Option<String>,
+    pub avatar_url: Option<String>,
+
+    pub context_type: String, // `Course` or `Account`
+    #[serde(default)]
+    pub course_id: Option<Id>,
+    #[serde(default)]
+    pub account_id: Option<Id>,
+    pub group_category_id: Id,
+    #[serde(default)]
+    pub role: Option<String>, // set for special groups such as student organized groups
+
+    pub is_public: bool,
+    pub followed_by_user: bool,
+    pub join_level: JoinLevel,
+    pub members_count: u32,
+    #[serde(default)]
+    pub concluded: bool,
+}
+
+impl Group {
+    /// The context of the group's own discussions, files and pages.
+    #[inline]
+    pub fn context(&self) -> Context {
+        Context::Group(self.id)
+    }
+}
+
+/// A Canvas Group Category, which is a set of groups that students are split into.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/group_categories.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GroupCategory {
+    pub id: Id,
+    pub name: String,
+    #[serde(default)]
+    pub role: Option<String>,
+
+    pub context_type: String,
+    #[serde(default)]
+    pub course_id: Option<Id>,
+    #[serde(default)]
+    pub account_id: Option<Id>,
+
+    pub self_signup: Option<SelfSignup>,
+    pub auto_leader: Option<AutoLeader>,
+    pub group_limit: Option<u32>,
+}
+
+/// A user's membership in a [`Group`].
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/groups.html#GroupMembership).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GroupMembership {
+    pub id: Id,
+    pub group_id: Id,
+    pub user_id: Id,
+    pub workflow_state: GroupMembershipWorkflowState,
+    pub moderator: bool,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JoinLevel {
+    ParentContextAutoJoin,
+    ParentContextRequest,
+    InvitationOnly,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelfSignup {
+    Enabled,
+    Restricted, // students may only join groups with members of their own section
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoLeader {
+    First,
+    Random,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupMembershipWorkflowState {
+    Accepted,
+    Invited,
+    Requested,
+}
@@ -0,0 +1,182 @@
+use crate::Id;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Late Policy, which automatically deducts points from late and missing submissions in a course.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/late_policy.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LatePolicy {
+    #[serde(default)]
+    pub id: Option<Id>, // absent if the course has never configured its late policy
+    pub course_id: Id,
+
+    pub missing_submission_deduction_enabled: bool,
+    pub missing_submission_deduction: f64, // as a percentage of the points possible
+
+    pub late_submission_deduction_enabled: bool,
+    pub late_submission_deduction: f64, // as a percentage of the points possible, per interval late
+    pub late_submission_interval: LateSubmissionInterval,
+
+    pub late_submission_minimum_percent_enabled: bool,
+    pub late_submission_minimum_percent: f64, // the lowest score late deductions can reduce a submission to
+}
+
+impl LatePolicy {
+    /// The percentage of the points possible deducted from a submission which is `seconds_late`,
+    /// assuming it would otherwise earn full marks.
+    pub fn late_deduction(&self, seconds_late: i64) -> f64 {
+        if !self.late_submission_deduction_enabled || seconds_late <= 0 {
+            return 0.0;
+        }
+
+        let intervals_late = self.intervals_late(seconds_late);
+        (self.late_submission_deduction * intervals_late as f64).min(self.maximum_deduction(100.0))
+    }
+
+    /// The points Canvas deducts from a submission which is `seconds_late` and scored `score` out of `points_possible`.
+    pub fn points_deducted(&self, score: f64, points_possible: f64, seconds_late: i64) -> f64 {
+        if !self.late_submission_deduction_enabled || points_possible <= 0.0 || seconds_late <= 0 {
+            return 0.0;
+        }
+
+        let intervals_late = self.intervals_late(seconds_late);
+        let percentage = (self.late_submission_deduction * intervals_late as f64)
+            .min(self.maximum_deduction(score / points_possible * 100.0));
+
+        percentage * points_possible / 100.0
+    }
+
+    /// Predict the deduction from a submission to an assignment due at `due_at` if it is submitted at `now`,
+    /// and how it would grow by waiting any longer.
+    pub fn predict(&self, due_at: DateTime<Utc>, now: DateTime<Utc>) -> DeductionPrediction {
+        let seconds_late = (now - due_at).num_seconds().max(0);
+        let intervals_late = self.intervals_late(seconds_late);
+        let interval_seconds = self.late_submission_interval.seconds();
+
+        let percentage = self.late_deduction(seconds_late);
+        // the deduction increases as soon as the next interval starts
+        let next_percentage = self.late_deduction((intervals_late + 1) * interval_seconds);
+
+        DeductionPrediction {
+            percentage,
+            next_percentage,
+            increases_at: (next_percentage > percentage)
+                .then(|| due_at + Duration::seconds(intervals_late * interval_seconds)),
+            missing_percentage: self
+                .missing_submission_deduction_enabled
+                .then_some(self.missing_submission_deduction),
+        }
+    }
+
+    fn intervals_late(&self, seconds_late: i64) -> i64 {
+        let interval_seconds = self.late_submission_interval.seconds();
+        (seconds_late.max(0) + interval_seconds - 1) / interval_seconds
+    }
+
+    /// The largest late deduction which keeps a score of `percentage` at or above the minimum.
+    fn maximum_deduction(&self, percentage: f64) -> f64 {
+        let minimum = if self.late_submission_minimum_percent_enabled {
+            self.late_submission_minimum_percent
+        } else {
+            0.0
+        };
+
+        (percentage - minimum).max(0.0)
+    }
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LateSubmissionInterval {
+    Day,
+    Hour,
+}
+
+impl LateSubmissionInterval {
+    pub fn seconds(self) -> i64 {
+        match self {
+            Self::Day => 24 * 60 * 60,
+            Self::Hour => 60 * 60,
+        }
+    }
+}
+
+/// The deductions a [`LatePolicy`] would make from a submission which would otherwise earn full marks.
+///
+/// All deductions are percentages of the points possible.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeductionPrediction {
+    pub percentage: f64,                     // if submitted now
+    pub next_percentage: f64,                // if submitted after `increases_at`
+    pub increases_at: Option<DateTime<Utc>>, // absent if the deduction can't grow any further
+    pub missing_percentage: Option<f64>, // if never submitted, present if missing submissions are deducted
+}
+
+/// The response to getting a [`LatePolicy`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LatePolicyResponse {
+    pub late_policy: LatePolicy,
+}
+
+#[cfg(test)]
+#[test]
+fn predict_late_deductions() {
+    use chrono::TimeZone;
+
+    let policy = LatePolicy {
+        id: Some(Id::new(1)),
+        course_id: Id::new(2),
+        missing_submission_deduction_enabled: true,
+        missing_submission_deduction: 80.0,
+        late_submission_deduction_enabled: true,
+        late_submission_deduction: 10.0,
+        late_submission_interval: LateSubmissionInterval::Day,
+        late_submission_minimum_percent_enabled: true,
+        late_submission_minimum_percent: 65.0,
+    };
+    let due_at = Utc.with_ymd_and_hms(2022, 3, 1, 23, 59, 0).unwrap();
+
+    let on_time = policy.predict(due_at, due_at - Duration::hours(1));
+    assert_eq!(on_time.percentage, 0.0);
+    assert_eq!(on_time.next_percentage, 10.0);
+    assert_eq!(on_time.increases_at, Some(due_at));
+    assert_eq!(on_time.missing_percentage, Some(80.0));
+
+    let one_second_late = policy.predict(due_at, due_at + Duration::seconds(1));
+    assert_eq!(one_second_late.percentage, 10.0);
+    assert_eq!(one_second_late.next_percentage, 20.0);
+    assert_eq!(
+        one_second_late.increases_at,
+        Some(due_at + Duration::days(1))
+    );
+
+    let three_days_late = policy.predict(due_at, due_at + Duration::days(3));
+    assert_eq!(three_days_late.percentage, 30.0);
+    assert_eq!(
+        three_days_late.increases_at,
+        Some(due_at + Duration::days(3))
+    );
+
+    // capped by the minimum percentage
+    let week_late = policy.predict(due_at, due_at + Duration::days(7));
+    assert_eq!(week_late.percentage, 35.0);
+    assert_eq!(week_late.next_percentage, 35.0);
+    assert_eq!(week_late.increases_at, None);
+
+    assert_eq!(policy.points_deducted(8.0, 10.0, 24 * 60 * 60), 1.0);
+    assert_eq!(policy.points_deducted(7.0, 10.0, 7 * 24 * 60 * 60), 0.5);
+    assert_eq!(policy.points_deducted(5.0, 10.0, 7 * 24 * 60 * 60), 0.0);
+}
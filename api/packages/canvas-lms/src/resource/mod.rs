@@ -1,13 +1,63 @@
+pub mod account;
+pub mod account_notification;
+pub mod activity_stream;
+pub mod analytics;
 pub mod assignment;
+pub mod assignment_group;
+pub mod calendar_event;
+pub mod communication_channel;
+pub mod content_export;
+pub mod conversation;
 pub mod course;
+pub mod discussion_topic;
 pub mod enrollment;
+pub mod external_tool;
+pub mod favorite;
+pub mod file;
 pub mod grading_period;
+pub mod grading_standard;
+pub mod group;
+pub mod late_policy;
+pub mod outcome;
+pub mod page;
+pub mod peer_review;
+pub mod planner;
+pub mod profile;
+pub mod progress;
+pub mod quiz;
+pub mod rubric;
+pub mod section;
 pub mod submission;
 pub mod user;
 
+pub use account::{Account, EnrollmentTerm};
+pub use account_notification::AccountNotification;
+pub use activity_stream::{ActivityStreamItem, TodoItem};
+pub use analytics::{AssignmentStatistics, StudentActivity, StudentCommunication};
 pub use assignment::Assignment;
-pub use course::Course;
+pub use assignment_group::AssignmentGroup;
+pub use calendar_event::{AppointmentGroup, CalendarEvent, UpcomingEvent};
+pub use communication_channel::{CommunicationChannel, NotificationPreference};
+pub use content_export::ContentExport;
+pub use conversation::Conversation;
+pub use course::{Course, CourseNickname};
+pub use discussion_topic::{DiscussionEntry, DiscussionTopic};
 pub use enrollment::{Enrollment, Grade};
+pub use external_tool::{ExternalTool, SessionlessLaunch};
+pub use favorite::Favorite;
+pub use file::{File, FileUpload, Folder};
 pub use grading_period::GradingPeriod;
-pub use submission::Submission;
-pub use user::User;
+pub use grading_standard::GradingStandard;
+pub use group::{Group, GroupCategory, GroupMembership};
+pub use late_policy::LatePolicy;
+pub use outcome::{Outcome, OutcomeGroup, OutcomeLink, OutcomeResult, OutcomeRollup};
+pub use page::Page;
+pub use peer_review::PeerReview;
+pub use planner::{PlannerItem, PlannerNote, PlannerOverride};
+pub use profile::Profile;
+pub use progress::Progress;
+pub use quiz::{Quiz, QuizQuestion, QuizSubmission};
+pub use rubric::{Rubric, RubricAssessment};
+pub use section::Section;
+pub use submission::{Submission, SubmissionComment};
+pub use user::{User, UserDisplay};
@@ -0,0 +1,290 @@
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A Canvas Outcome, which is a learning standard that assignments and rubrics assess mastery of.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/outcomes.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Outcome {
+    pub id: Id,
+    pub url: String,
+    pub context_id: Option<Id>,
+    pub context_type: Option<String>,
+
+    pub title: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub vendor_guid: Option<String>,
+
+    // the following are present when fetching full outcomes
+    #[serde(default)]
+    pub points_possible: Option<f64>,
+    #[serde(default)]
+    pub mastery_points: Option<f64>,
+    #[serde(default)]
+    pub calculation_method: Option<CalculationMethod>,
+    #[serde(default)]
+    pub calculation_int: Option<u32>, // the weight or count used by some calculation methods
+    #[serde(default)]
+    pub ratings: Vec<OutcomeRating>,
+    #[serde(default)]
+    pub assessed: Option<bool>,
+
+    pub can_edit: bool,
+}
+
+impl Outcome {
+    /// Whether a score meets the outcome's mastery threshold, if it has one.
+    pub fn is_mastered_by(&self, score: f64) -> Option<bool> {
+        self.mastery_points
+            .map(|mastery_points| score >= mastery_points)
+    }
+}
+
+/// A level of mastery on an [`Outcome`]'s scale.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeRating {
+    pub description: String,
+    pub points: f64,
+}
+
+/// How Canvas combines a student's results for an [`Outcome`] into a single score.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalculationMethod {
+    DecayingAverage,
+    NMastery,
+    Latest,
+    Highest,
+    Average,
+}
+
+/// A Canvas Outcome Group, which organizes outcomes into a tree.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/outcome_groups.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OutcomeGroup {
+    pub id: Id,
+    pub url: String,
+
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub vendor_guid: Option<String>,
+
+    // the following are absent on groups embedded in other resources
+    #[serde(default)]
+    pub context_id: Option<Id>,
+    #[serde(default)]
+    pub context_type: Option<String>,
+    #[serde(default)]
+    pub parent_outcome_group: Option<OutcomeGroupParent>, // absent on root groups
+
+    pub subgroups_url: String,
+    pub outcomes_url: String,
+    pub can_edit: bool,
+}
+
+/// The parent of an [`OutcomeGroup`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OutcomeGroupParent {
+    pub id: Id,
+    pub title: String,
+}
+
+/// The placement of an [`Outcome`] in an [`OutcomeGroup`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeLink {
+    pub url: String,
+    pub context_id: Id,
+    pub context_type: String,
+
+    pub outcome_group: OutcomeGroup,
+    pub outcome: Outcome,
+
+    #[serde(default)]
+    pub assessed: bool,
+    #[serde(default)]
+    pub can_unlink: Option<bool>,
+}
+
+/// A student's score for an [`Outcome`] from a single alignment, such as an assignment's rubric.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/outcome_results.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeResult {
+    pub id: Id,
+
+    pub score: Option<f64>,
+    #[serde(default)]
+    pub possible: Option<f64>,
+    #[serde(default)]
+    pub percent: Option<f64>,
+    #[serde(default)]
+    pub mastery: Option<bool>,
+    #[serde(default)]
+    pub hidden: bool,
+
+    pub submitted_or_assessed_at: Option<DateTime<Utc>>,
+    pub links: OutcomeResultLinks,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OutcomeResultLinks {
+    #[serde(deserialize_with = "id_from_str")]
+    pub user: Id,
+    #[serde(deserialize_with = "id_from_str")]
+    pub learning_outcome: Id,
+    pub alignment: String, // of the form `assignment_<id>`
+    #[serde(default)]
+    pub assignment: Option<String>,
+}
+
+/// The response to listing [`OutcomeResult`]s.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeResults {
+    pub outcome_results: Vec<OutcomeResult>,
+}
+
+/// A student's combined scores for each [`Outcome`] in a course.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/outcome_results.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeRollup {
+    pub scores: Vec<OutcomeRollupScore>,
+    pub links: OutcomeRollupLinks,
+}
+
+impl OutcomeRollup {
+    /// The combined score for an outcome, if the student has been assessed on it.
+    pub fn score(&self, outcome_id: Id) -> Option<&OutcomeRollupScore> {
+        self.scores
+            .iter()
+            .find(|score| score.links.outcome == outcome_id)
+    }
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeRollupScore {
+    pub score: f64,
+    pub count: u32, // the number of results combined into the score
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub links: OutcomeRollupScoreLinks,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OutcomeRollupScoreLinks {
+    #[serde(deserialize_with = "id_from_str")]
+    pub outcome: Id,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OutcomeRollupLinks {
+    #[serde(deserialize_with = "id_from_str")]
+    pub user: Id,
+    #[serde(default)]
+    pub section: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// The response to listing [`OutcomeRollup`]s.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeRollups {
+    pub rollups: Vec<OutcomeRollup>,
+}
+
+/// Deserialize an ID which Canvas sends as a string, as it does in the `links` of outcome results.
+///
+/// IDs which have already been deserialized and reserialized as numbers are accepted too.
+fn id_from_str<'de, D>(deserializer: D) -> Result<Id, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StrOrId {
+        Str(String),
+        Id(Id),
+    }
+
+    match StrOrId::deserialize(deserializer)? {
+        StrOrId::Str(s) => s.parse().map_err(serde::de::Error::custom),
+        StrOrId::Id(id) => Ok(id),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn deserialize_outcome_rollup() {
+    let rollups: OutcomeRollups = serde_json::from_str(
+        r#"{
+            "rollups": [{
+                "scores": [{ "score": 3.5, "count": 2, "links": { "outcome": "7" } }],
+                "links": { "user": "42", "section": "1", "status": "active" }
+            }]
+        }"#,
+    )
+    .unwrap();
+
+    let rollup = &rollups.rollups[0];
+    assert_eq!(rollup.links.user, Id::new(42));
+    assert_eq!(rollup.score(Id::new(7)).map(|score| score.score), Some(3.5));
+    assert_eq!(rollup.score(Id::new(8)), None);
+}
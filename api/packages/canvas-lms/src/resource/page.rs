@@ -0,0 +1,43 @@
+use super::{assignment::LockInfo, UserDisplay};
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Page, which is a wiki page within a course or group.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/pages.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Page {
+    pub page_id: Id,
+    pub url: String, // the page's unique slug within its context
+    pub title: String,
+    #[serde(default)]
+    pub html_url: Option<String>,
+
+    #[serde(default)]
+    pub body: Option<String>, // present when getting a single page
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub todo_date: Option<DateTime<Utc>>,
+
+    pub published: bool,
+    #[serde(default)]
+    pub front_page: bool,
+    #[serde(default)]
+    pub editing_roles: Option<String>, // a comma-separated list of roles
+    #[serde(default)]
+    pub last_edited_by: Option<UserDisplay>,
+
+    #[serde(default)]
+    pub locked_for_user: bool,
+    #[serde(default)]
+    pub lock_info: Option<LockInfo>,
+    #[serde(default)]
+    pub lock_explanation: Option<String>,
+}
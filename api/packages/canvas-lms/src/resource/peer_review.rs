@@ -0,0 +1,46 @@
+use super::{submission::SubmissionComment, UserDisplay};
+use crate::Id;
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Peer Review, which is a request for a student to review another student's submission.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/peer_reviews.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PeerReview {
+    pub id: Id,
+
+    pub assessor_id: Id, // the reviewer
+    #[serde(default)]
+    pub user_id: Option<Id>, // the student whose submission is reviewed, absent if reviews are anonymous
+    pub asset_id: Id, // the reviewed submission
+    pub asset_type: String,
+    pub workflow_state: PeerReviewWorkflowState,
+
+    #[serde(default)]
+    pub user: Option<UserDisplay>, // present on include[]=user, unless reviews are anonymous
+    #[serde(default)]
+    pub assessor: Option<UserDisplay>, // present on include[]=user
+    #[serde(default)]
+    pub submission_comments: Vec<SubmissionComment>, // present on include[]=submission_comments
+}
+
+impl PeerReview {
+    pub fn is_outstanding(&self) -> bool {
+        self.workflow_state == PeerReviewWorkflowState::Assigned
+    }
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PeerReviewWorkflowState {
+    Assigned,
+    Completed,
+}
@@ -0,0 +1,218 @@
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// An item on a user's planner, such as an assignment, a discussion topic or a [`PlannerNote`].
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/planner.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannerItem {
+    pub plannable_id: Id,
+    pub plannable_type: PlannableType,
+    pub plannable_date: DateTime<Utc>,
+    pub plannable: Plannable,
+    #[serde(default)]
+    pub html_url: Option<String>,
+
+    #[serde(default)]
+    pub context_type: Option<String>,
+    #[serde(default)]
+    pub course_id: Option<Id>,
+    #[serde(default)]
+    pub group_id: Option<Id>,
+    #[serde(default)]
+    pub context_name: Option<String>,
+
+    pub planner_override: Option<PlannerOverride>,
+    #[serde(default)]
+    pub new_activity: bool,
+    #[serde(default, deserialize_with = "false_as_none")]
+    pub submissions: Option<PlannerSubmissionStatus>, // Canvas sends `false` for items which can't be submitted
+}
+
+impl PlannerItem {
+    /// Whether the user has marked the item as complete, whether or not it was submitted.
+    pub fn is_marked_complete(&self) -> bool {
+        self.planner_override
+            .as_ref()
+            .is_some_and(|planner_override| planner_override.marked_complete)
+    }
+
+    /// Whether the user has dismissed the item from their opportunities list.
+    pub fn is_dismissed(&self) -> bool {
+        self.planner_override
+            .as_ref()
+            .is_some_and(|planner_override| planner_override.dismissed)
+    }
+}
+
+/// The fields common to every kind of object which can appear on a planner.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Plannable {
+    pub id: Id,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub details: Option<String>, // present on planner notes
+
+    #[serde(default)]
+    pub due_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub todo_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub points_possible: Option<f64>,
+
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// The state of the user's submission for a [`PlannerItem`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlannerSubmissionStatus {
+    #[serde(default)]
+    pub submitted: bool,
+    #[serde(default)]
+    pub excused: bool,
+    #[serde(default)]
+    pub graded: bool,
+    #[serde(default)]
+    pub late: bool,
+    #[serde(default)]
+    pub missing: bool,
+    #[serde(default)]
+    pub needs_grading: bool,
+    #[serde(default)]
+    pub has_feedback: bool,
+}
+
+/// A to-do item created by a user on their own planner.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/planner.html#PlannerNote).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlannerNote {
+    pub id: Id,
+    pub user_id: Id,
+    pub course_id: Option<Id>,
+
+    pub title: String,
+    pub description: Option<String>,
+    pub todo_date: DateTime<Utc>,
+    pub workflow_state: PlannerWorkflowState,
+
+    #[serde(default)]
+    pub linked_object_type: Option<PlannableType>,
+    #[serde(default)]
+    pub linked_object_id: Option<Id>,
+    #[serde(default)]
+    pub linked_object_html_url: Option<String>,
+}
+
+/// A user's override of the completion or visibility of a [`PlannerItem`].
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/planner.html#PlannerOverride).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlannerOverride {
+    pub id: Id,
+    pub user_id: Id,
+    pub plannable_type: PlannableType,
+    pub plannable_id: Id,
+    #[serde(default)]
+    pub assignment_id: Option<Id>,
+
+    pub marked_complete: bool,
+    pub dismissed: bool,
+
+    pub workflow_state: PlannerWorkflowState,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlannableType {
+    Announcement,
+    Assignment,
+    DiscussionTopic,
+    Quiz,
+    WikiPage,
+    PlannerNote,
+    CalendarEvent,
+    AssessmentRequest,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlannerWorkflowState {
+    Active,
+    Deleted,
+}
+
+fn false_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OrFalse<T> {
+        Bool(#[allow(dead_code)] bool),
+        Value(T),
+    }
+
+    Ok(match Option::<OrFalse<T>>::deserialize(deserializer)? {
+        Some(OrFalse::Value(value)) => Some(value),
+        Some(OrFalse::Bool(_)) | None => None,
+    })
+}
+
+#[cfg(test)]
+#[test]
+fn deserialize_planner_item_without_submissions() {
+    let item: PlannerItem = serde_json::from_str(
+        r#"{
+            "plannable_id": 12,
+            "plannable_type": "planner_note",
+            "plannable_date": "2022-03-01T12:00:00Z",
+            "plannable": { "id": 12, "title": "Study for midterm", "todo_date": "2022-03-01T12:00:00Z" },
+            "planner_override": null,
+            "submissions": false
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(item.plannable_type, PlannableType::PlannerNote);
+    assert_eq!(item.submissions, None);
+    assert!(!item.is_marked_complete());
+}
@@ -0,0 +1,48 @@
+use crate::Id;
+use serde::{Deserialize, Serialize};
+
+/// A user's Canvas profile.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/users.html#Profile).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Profile {
+    pub id: Id,
+
+    pub name: String,
+    pub short_name: String,
+    pub sortable_name: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub bio: Option<String>,
+    #[serde(default)]
+    pub pronouns: Option<String>,
+
+    #[serde(default)]
+    pub primary_email: Option<String>,
+    #[serde(default)]
+    pub login_id: Option<String>,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+
+    #[serde(default)]
+    pub time_zone: Option<String>, // an IANA time zone name such as `America/Denver`
+    #[serde(default)]
+    pub locale: Option<String>,
+    #[serde(default)]
+    pub calendar: Option<CalendarLink>,
+}
+
+/// A link to a user's calendar feed.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CalendarLink {
+    pub ics: String,
+}
@@ -0,0 +1,54 @@
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Progress, which tracks an asynchronous job such as a content export or a bulk grade update.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/progress.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Progress {
+    pub id: Id,
+    pub context_id: Id,
+    pub context_type: String,
+    #[serde(default)]
+    pub user_id: Option<Id>,
+
+    pub tag: String, // the kind of job, such as `content_export`
+    #[serde(default)]
+    pub completion: Option<f64>, // as a percentage
+    pub workflow_state: ProgressWorkflowState,
+    #[serde(default)]
+    pub message: Option<String>,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+
+    pub url: String, // the API URL to poll for updates
+}
+
+impl Progress {
+    /// Whether the job has stopped, either because it completed or because it failed.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.workflow_state,
+            ProgressWorkflowState::Completed | ProgressWorkflowState::Failed
+        )
+    }
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgressWorkflowState {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
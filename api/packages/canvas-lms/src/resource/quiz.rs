@@ -0,0 +1,207 @@
+use super::assignment::LockInfo;
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Classic Quiz.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/quizzes.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Quiz {
+    pub id: Id,
+
+    pub title: String,
+    pub description: Option<String>,
+    pub html_url: String,
+
+    pub quiz_type: QuizType,
+    #[serde(default)]
+    pub assignment_id: Option<Id>, // set if the quiz is graded
+    pub assignment_group_id: Option<Id>,
+
+    pub due_at: Option<DateTime<Utc>>,
+    pub lock_at: Option<DateTime<Utc>>,
+    pub unlock_at: Option<DateTime<Utc>>,
+
+    pub time_limit: Option<u32>, // in minutes
+    pub allowed_attempts: i32,   // -1 if unlimited
+    pub question_count: u32,
+    pub points_possible: Option<f64>,
+
+    pub shuffle_answers: bool,
+    pub one_question_at_a_time: bool,
+    pub cant_go_back: Option<bool>,
+    pub hide_results: Option<HideResults>,
+    pub show_correct_answers: bool,
+    pub show_correct_answers_at: Option<DateTime<Utc>>,
+    pub hide_correct_answers_at: Option<DateTime<Utc>>,
+
+    pub published: bool,
+    pub locked_for_user: bool,
+    #[serde(default)]
+    pub lock_info: Option<LockInfo>,
+    #[serde(default)]
+    pub lock_explanation: Option<String>,
+}
+
+impl Quiz {
+    /// Whether the quiz can be taken an unlimited number of times.
+    #[inline]
+    pub fn has_unlimited_attempts(&self) -> bool {
+        self.allowed_attempts < 0
+    }
+}
+
+/// A user's attempt(s) at a [`Quiz`].
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/quiz_submissions.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuizSubmission {
+    pub id: Id,
+    pub quiz_id: Id,
+    pub user_id: Id,
+    pub submission_id: Option<Id>,
+    #[serde(default)]
+    pub html_url: Option<String>,
+
+    pub attempt: Option<u32>,
+    pub extra_attempts: Option<u32>,
+    pub extra_time: Option<u32>, // in minutes
+
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+    pub time_spent: Option<u32>, // in seconds
+
+    pub score: Option<f64>,
+    pub score_before_regrade: Option<f64>,
+    pub kept_score: Option<f64>,
+    pub fudge_points: Option<f64>,
+    #[serde(default)]
+    pub quiz_points_possible: Option<f64>,
+
+    pub has_seen_results: Option<bool>,
+    pub overdue_and_needs_submission: bool,
+    pub workflow_state: QuizSubmissionWorkflowState,
+}
+
+/// The wrapper object in which Canvas returns [`QuizSubmission`]s.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuizSubmissions {
+    pub quiz_submissions: Vec<QuizSubmission>,
+}
+
+/// A question in a [`Quiz`].
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/quiz_questions.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuizQuestion {
+    pub id: Id,
+    pub quiz_id: Id,
+    pub position: Option<u32>,
+
+    pub question_name: String,
+    pub question_type: QuestionType,
+    pub question_text: String,
+    pub points_possible: Option<f64>,
+
+    #[serde(default)]
+    pub correct_comments: Option<String>,
+    #[serde(default)]
+    pub incorrect_comments: Option<String>,
+    #[serde(default)]
+    pub neutral_comments: Option<String>,
+
+    #[serde(default)]
+    pub answers: Vec<QuizAnswer>,
+}
+
+/// A possible answer to a [`QuizQuestion`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuizAnswer {
+    pub id: Id,
+
+    #[serde(default, alias = "answer_text")]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub html: Option<String>,
+    #[serde(default, alias = "answer_weight")]
+    pub weight: Option<f64>, // 100 for correct answers, 0 otherwise
+    #[serde(default, alias = "answer_comments")]
+    pub comments: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuizType {
+    PracticeQuiz,
+    Assignment,
+    GradedSurvey,
+    Survey,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HideResults {
+    Always,
+    UntilAfterLastAttempt,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuizSubmissionWorkflowState {
+    Untaken,
+    PendingReview,
+    Complete,
+    SettingsOnly,
+    Preview,
+}
+
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuestionType {
+    CalculatedQuestion,
+    EssayQuestion,
+    FileUploadQuestion,
+    FillInMultipleBlanksQuestion,
+    MatchingQuestion,
+    MultipleAnswersQuestion,
+    MultipleChoiceQuestion,
+    MultipleDropdownsQuestion,
+    NumericalQuestion,
+    ShortAnswerQuestion,
+    TextOnlyQuestion,
+    TrueFalseQuestion,
+}
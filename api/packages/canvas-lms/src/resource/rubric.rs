@@ -0,0 +1,123 @@
+use crate::Id;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A Canvas Rubric.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/rubrics.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rubric {
+    pub id: Id,
+    pub title: String,
+    pub context_id: Id,
+    pub context_type: String,
+
+    pub points_possible: f64,
+    #[serde(default)]
+    pub reusable: bool,
+    #[serde(default)]
+    pub read_only: bool,
+    #[serde(default)]
+    pub free_form_criterion_comments: bool,
+    #[serde(default)]
+    pub hide_score_total: bool,
+
+    pub data: Vec<RubricCriterion>,
+}
+
+/// The settings of the [`Rubric`] attached to an assignment.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RubricSettings {
+    pub id: Id,
+    pub title: String,
+    pub points_possible: f64,
+    #[serde(default)]
+    pub free_form_criterion_comments: bool,
+    #[serde(default)]
+    pub hide_score_total: bool,
+    #[serde(default)]
+    pub hide_points: bool,
+}
+
+/// A single row of a [`Rubric`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RubricCriterion {
+    pub id: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub long_description: Option<String>,
+    pub points: f64,
+    #[serde(default)]
+    pub criterion_use_range: bool, // ratings cover the range down to the next rating's points
+    #[serde(default)]
+    pub ignore_for_scoring: bool,
+    #[serde(default)]
+    pub outcome_id: Option<Id>, // present if the criterion is linked to a learning outcome
+
+    pub ratings: Vec<RubricRating>,
+}
+
+/// One of the possible ratings for a [`RubricCriterion`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RubricRating {
+    pub id: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub long_description: Option<String>,
+    pub points: f64,
+}
+
+/// An assessment of a submission against a [`Rubric`], keyed by [`RubricCriterion`] ID.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RubricAssessment(pub HashMap<String, CriterionAssessment>);
+
+impl RubricAssessment {
+    /// Get the assessment of a single criterion.
+    pub fn criterion(&self, criterion_id: &str) -> Option<&CriterionAssessment> {
+        self.0.get(criterion_id)
+    }
+
+    /// The total points awarded across every assessed criterion.
+    pub fn points(&self) -> f64 {
+        self.0
+            .values()
+            .filter_map(|assessment| assessment.points)
+            .sum()
+    }
+}
+
+/// The assessment of a single [`RubricCriterion`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CriterionAssessment {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub points: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+}
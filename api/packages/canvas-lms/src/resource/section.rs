@@ -0,0 +1,26 @@
+use crate::Id;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Canvas Section, which is a subset of a course's enrollments.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/sections.html).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Section {
+    pub id: Id,
+    pub name: String,
+    pub course_id: Id,
+    pub nonxlist_course_id: Option<Id>, // set if the section has been cross-listed from another course
+
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub restrict_enrollments_to_section_dates: Option<bool>,
+
+    #[serde(default)]
+    pub total_students: Option<u32>, // present on include[]=total_students
+}
@@ -1,4 +1,4 @@
-use super::{Assignment, Course};
+use super::{Assignment, Course, File, RubricAssessment, User};
 use crate::Id;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -12,10 +12,14 @@ use serde::{Deserialize, Serialize};
 )]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Submission {
+    #[serde(default)]
+    pub id: Option<Id>, // absent on submissions cached before it was added
     pub course: Option<Course>,
     pub assignment_id: Id,
     pub assignment: Option<Assignment>,
     pub user_id: Id,
+    #[serde(default)]
+    pub user: Option<User>, // present on include[]=user
     pub attempt: Option<u32>,
 
     #[serde(default)]
@@ -44,6 +48,57 @@ pub struct Submission {
 
     pub grade: Option<String>,
     pub score: Option<f64>,
+
+    #[serde(default)]
+    pub attachments: Vec<File>, // present on `online_upload` submissions
+    #[serde(default)]
+    pub media_comment: Option<MediaComment>, // present on `media_recording` submissions
+
+    #[serde(default)]
+    pub rubric_assessment: Option<RubricAssessment>, // present on include[]=rubric_assessment
+    #[serde(default)]
+    pub submission_comments: Vec<SubmissionComment>, // present on include[]=submission_comments
+    #[serde(default)]
+    pub submission_history: Vec<Submission>, // every attempt, present on include[]=submission_history
+}
+
+/// A comment left on a [`Submission`] by the student, a grader or a peer reviewer.
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SubmissionComment {
+    pub id: Id,
+    pub author_id: Option<Id>, // absent on anonymous peer reviews
+    pub author_name: String,
+    pub comment: String,
+
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub edited_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub attempt: Option<u32>, // the submission attempt the comment was left on
+
+    #[serde(default)]
+    pub attachments: Vec<File>,
+    #[serde(default)]
+    pub media_comment: Option<MediaComment>,
+}
+
+/// An audio or video recording attached to a [`Submission`] or [`SubmissionComment`].
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MediaComment {
+    pub media_id: String,
+    pub media_type: String, // `audio` or `video`
+    pub content_type: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    pub url: String,
 }
 
 #[cfg_attr(
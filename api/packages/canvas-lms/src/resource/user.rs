@@ -1,3 +1,4 @@
+use super::enrollment::InlineEnrollment;
 use crate::Id;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -9,7 +10,7 @@ use serde::{Deserialize, Serialize};
     feature = "typescript-definitions",
     derive(typescript_definitions::TypeScriptify)
 )]
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct User {
     pub id: Id,
 
@@ -21,4 +22,36 @@ pub struct User {
 
     pub locale: Option<String>,
     pub effective_locale: Option<String>,
+
+    #[serde(default)]
+    pub avatar_url: Option<String>, // present on include[]=avatar_url
+    #[serde(default)]
+    pub email: Option<String>, // present on include[]=email, if visible to the current user
+    #[serde(default)]
+    pub pronouns: Option<String>,
+
+    #[serde(default)]
+    pub enrollments: Vec<InlineEnrollment>, // present on include[]=enrollments
+}
+
+/// An abbreviated user, as embedded in other resources.
+///
+/// Refer to [Canvas's API documentation](https://canvas.instructure.com/doc/api/users.html#UserDisplay).
+#[cfg_attr(
+    feature = "typescript-definitions",
+    derive(typescript_definitions::TypeScriptify)
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserDisplay {
+    #[serde(default)]
+    pub id: Option<Id>, // absent for anonymous authors
+
+    #[serde(alias = "short_name")]
+    pub display_name: String,
+    #[serde(default)]
+    pub avatar_image_url: Option<String>,
+    #[serde(default)]
+    pub html_url: Option<String>,
+    #[serde(default)]
+    pub pronouns: Option<String>,
 }
@@ -34,33 +34,158 @@ macro_rules! type_definitions_for {
 }
 
 pub fn definitions() -> String {
-    use canvas_lms::{id, resource::*};
+    use canvas_lms::{context, id, resource::*};
 
     type_definitions_for!(
         id::Id,
+        context::Context,
+        account::Account,
+        account::EnrollmentTerm,
+        account::TermOverride,
+        account::TermWorkflowState,
+        account_notification::AccountNotification,
+        account_notification::NotificationIcon,
+        activity_stream::ActivityStreamItem,
+        activity_stream::Activity,
+        activity_stream::DiscussionTopicActivity,
+        activity_stream::AnnouncementActivity,
+        activity_stream::ConversationActivity,
+        activity_stream::MessageActivity,
+        activity_stream::SubmissionActivity,
+        activity_stream::ConferenceActivity,
+        activity_stream::CollaborationActivity,
+        activity_stream::AssessmentRequestActivity,
+        activity_stream::TodoItem,
+        activity_stream::TodoType,
+        analytics::StudentActivity,
+        analytics::Participation,
+        analytics::AssignmentStatistics,
+        analytics::SubmissionStatistics,
+        analytics::StudentCommunication,
+        analytics::MessageCounts,
         assignment::Assignment,
         assignment::AssignmentOverride,
         assignment::GradingType,
         assignment::ScoreStatistics,
         assignment::LockInfo,
+        assignment::ExternalToolTagAttributes,
+        assignment_group::AssignmentGroup,
+        assignment_group::AssignmentGroupRules,
+        calendar_event::CalendarEvent,
+        calendar_event::AssignmentEvent,
+        calendar_event::UpcomingEvent,
+        calendar_event::AppointmentGroup,
+        calendar_event::ReservedTime,
+        calendar_event::CalendarEventWorkflowState,
+        calendar_event::AppointmentGroupWorkflowState,
+        calendar_event::ParticipantType,
+        calendar_event::ParticipantVisibility,
+        communication_channel::CommunicationChannel,
+        communication_channel::NotificationPreference,
+        communication_channel::ChannelType,
+        communication_channel::ChannelWorkflowState,
+        communication_channel::NotificationFrequency,
+        content_export::ContentExport,
+        content_export::ExportAttachment,
+        content_export::ExportType,
+        content_export::ExportWorkflowState,
+        conversation::Conversation,
+        conversation::ConversationMessage,
+        conversation::ConversationParticipant,
+        conversation::ConversationWorkflowState,
+        conversation::ConversationProperty,
         course::Course,
+        course::CourseNickname,
         course::CourseWorkflowState,
         course::CourseView,
         course::Term,
         course::CourseProgress,
         course::CourseFormat,
         course::Permissions,
+        discussion_topic::DiscussionTopic,
+        discussion_topic::DiscussionEntry,
+        discussion_topic::FullTopicView,
+        discussion_topic::ThreadedEntry,
+        discussion_topic::DiscussionType,
+        discussion_topic::ReadState,
         enrollment::Enrollment,
         enrollment::InlineEnrollment,
         enrollment::EnrollmentState,
         enrollment::EnrollmentType,
         enrollment::EnrollmentRole,
         enrollment::Grade,
+        external_tool::ExternalTool,
+        external_tool::ToolPlacement,
+        external_tool::PrivacyLevel,
+        external_tool::SessionlessLaunch,
+        favorite::Favorite,
+        favorite::FavoriteContext,
+        file::File,
+        file::Folder,
+        file::FolderTree,
+        file::FileUpload,
         grading_period::GradingPeriod,
+        grading_standard::GradingStandard,
+        grading_standard::GradingSchemeEntry,
+        grading_standard::GradingStandardContext,
+        group::Group,
+        group::GroupCategory,
+        group::GroupMembership,
+        group::JoinLevel,
+        group::SelfSignup,
+        group::AutoLeader,
+        group::GroupMembershipWorkflowState,
+        late_policy::LatePolicy,
+        late_policy::LateSubmissionInterval,
+        late_policy::DeductionPrediction,
+        outcome::Outcome,
+        outcome::OutcomeRating,
+        outcome::CalculationMethod,
+        outcome::OutcomeGroup,
+        outcome::OutcomeGroupParent,
+        outcome::OutcomeLink,
+        outcome::OutcomeResult,
+        outcome::OutcomeResultLinks,
+        outcome::OutcomeRollup,
+        outcome::OutcomeRollupScore,
+        outcome::OutcomeRollupScoreLinks,
+        outcome::OutcomeRollupLinks,
+        page::Page,
+        peer_review::PeerReview,
+        peer_review::PeerReviewWorkflowState,
+        planner::PlannerItem,
+        planner::Plannable,
+        planner::PlannerSubmissionStatus,
+        planner::PlannerNote,
+        planner::PlannerOverride,
+        planner::PlannableType,
+        planner::PlannerWorkflowState,
+        profile::Profile,
+        profile::CalendarLink,
+        progress::Progress,
+        progress::ProgressWorkflowState,
+        quiz::Quiz,
+        quiz::QuizSubmission,
+        quiz::QuizQuestion,
+        quiz::QuizAnswer,
+        quiz::QuizType,
+        quiz::HideResults,
+        quiz::QuizSubmissionWorkflowState,
+        quiz::QuestionType,
+        rubric::Rubric,
+        rubric::RubricSettings,
+        rubric::RubricCriterion,
+        rubric::RubricRating,
+        rubric::RubricAssessment,
+        rubric::CriterionAssessment,
+        section::Section,
         submission::Submission,
+        submission::SubmissionComment,
+        submission::MediaComment,
         submission::SubmissionType,
         submission::SubmissionWorkflowState,
         submission::LatePolicyStatus,
         user::User,
+        user::UserDisplay,
     )
 }
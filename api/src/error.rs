@@ -22,6 +22,14 @@ pub enum Error {
         #[source]
         source: canvas_lms::client::Error,
     },
+
+    #[error("invalid value for parameter `{name}`")]
+    #[diagnostic(code(oil::invalid_parameter))]
+    InvalidParameter {
+        name: &'static str,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -40,6 +48,16 @@ impl Error {
             source,
         }
     }
+
+    pub fn invalid_parameter(
+        name: &'static str,
+        source: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        Self::InvalidParameter {
+            name,
+            source: Box::new(source),
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -79,6 +97,7 @@ impl ResponseError for Error {
         match self {
             Self::Database { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             Self::Canvas { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::InvalidParameter { .. } => StatusCode::BAD_REQUEST,
         }
     }
 
@@ -0,0 +1,154 @@
+use super::{assignment::CachedAssignment, get_view, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    endpoint::analytics as endpoint,
+    resource::{assignment::ScoreStatistics, AssignmentStatistics},
+    Id,
+};
+use chrono::NaiveDate;
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, OpenApi};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+/// How the user has engaged with a course and where their scores stand within it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CourseAnalytics {
+    pub activity: Vec<ActivityDay>, // oldest first
+    pub assignments: Vec<AssignmentStanding>,
+}
+
+/// The user's activity in a course on a single day, in UTC.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ActivityDay {
+    pub date: NaiveDate,
+    pub page_views: u32,
+    pub participations: u32,
+    pub instructor_messages: u32,
+    pub student_messages: u32,
+}
+
+/// Where the user's score on an assignment stands within the course.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentStanding {
+    #[serde(flatten)]
+    pub statistics: AssignmentStatistics,
+    pub score_statistics: Option<ScoreStatistics>, // the mean, which analytics doesn't give, is only present when cached
+    pub quartile: Option<u8>,
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+    assignments: Collection<DbResource<CachedAssignment>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            assignments: database.collection("assignments"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Get the user's daily activity in a course, along with how their score on each assignment compares to the course.
+    ///
+    /// Each assignment's score statistics are taken from the assignment cache.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/analytics",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_analytics(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Any<CourseAnalytics>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+        let client = view.client(self.http.clone());
+
+        let canvas_course_id = Id::new(course_id.0 as u64);
+        let user_id = Id::new(view.student_id());
+
+        let (activity, assignments, communication) = future::try_join3(
+            endpoint::get_student_activity(&client, canvas_course_id, user_id)
+                .send()
+                .map_err(|err| Error::canvas_while("fetching activity analytics", err)),
+            endpoint::list_student_assignments(&client, canvas_course_id, user_id)
+                .send()
+                .map_err(|err| Error::canvas_while("fetching assignment analytics", err)),
+            endpoint::get_student_communication(&client, canvas_course_id, user_id)
+                .send()
+                .map_err(|err| Error::canvas_while("fetching communication analytics", err)),
+        )
+        .await?;
+
+        let mut days = BTreeMap::<NaiveDate, ActivityDay>::new();
+        for (hour, page_views) in activity.page_views {
+            day(&mut days, hour.naive_utc().date()).page_views += page_views;
+        }
+        for participation in activity.participations {
+            day(&mut days, participation.created_at.naive_utc().date()).participations += 1;
+        }
+        for (date, counts) in communication.0 {
+            let entry = day(&mut days, date);
+            entry.instructor_messages += counts.instructor_messages;
+            entry.student_messages += counts.student_messages;
+        }
+
+        let mut score_statistics = HashMap::new();
+        self.assignments
+            .find(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating assignment cursor", err))?
+            .try_for_each(|assignment| {
+                let assignment = assignment.resource.assignment;
+                if let Some(statistics) = assignment.score_statistics {
+                    score_statistics.insert(assignment.id, statistics);
+                }
+                future::ready(Ok(()))
+            })
+            .await
+            .map_err(|err| Error::database_while("collecting assignments", err))?;
+
+        let assignments = assignments
+            .into_iter()
+            .map(|statistics| AssignmentStanding {
+                score_statistics: score_statistics.remove(&statistics.assignment_id),
+                quartile: statistics.quartile(),
+                statistics,
+            })
+            .collect();
+
+        Ok(Json(Any(CourseAnalytics {
+            activity: days.into_values().collect(),
+            assignments,
+        })))
+    }
+}
+
+/// Get the activity for a day, starting it if it has none yet.
+fn day(days: &mut BTreeMap<NaiveDate, ActivityDay>, date: NaiveDate) -> &mut ActivityDay {
+    days.entry(date).or_insert_with(|| ActivityDay {
+        date,
+        ..Default::default()
+    })
+}
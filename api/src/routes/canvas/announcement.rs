@@ -0,0 +1,312 @@
+use super::{get_view, replace_cache, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    endpoint::{
+        account_notification as notification_endpoint,
+        announcement::{self as endpoint, AnnouncementFilter},
+    },
+    resource::{AccountNotification, Course, DiscussionTopic},
+    Context, Id,
+};
+use chrono::Utc;
+use futures::prelude::*;
+use mongodb::{
+    options::{FindOptions, UpdateOptions},
+    Collection, Database,
+};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, OpenApi};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// An announcement in a view's feed along with whether the user has read it in Paint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedAnnouncement {
+    pub announcement: DiscussionTopic,
+    pub read: bool,
+}
+
+/// A record that the user has read an announcement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DbAnnouncementRead {
+    view: bson::Uuid,
+    announcement_id: Id,
+    read_at: bson::DateTime,
+}
+
+pub struct Api {
+    db_client: mongodb::Client,
+    views: Collection<DbView>,
+    courses: Collection<DbResource<Course>>,
+    announcements: Collection<DbResource<DiscussionTopic>>,
+    announcement_reads: Collection<DbAnnouncementRead>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            db_client: db_client.clone(),
+            views: database.collection("views"),
+            courses: database.collection("courses"),
+            announcements: database.collection("announcements"),
+            announcement_reads: database.collection("announcement_reads"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the announcement cache for all of the view's cached courses.
+    ///
+    /// Every announcement posted since the earliest course was created or started, whichever came first, is cached rather than only those in Canvas's default window of the last two weeks.
+    #[oai(
+        path = "/views/:view_id/announcements/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn update_announcements(&self, claims: Claims, view_id: Path<Uuid>) -> poem::Result<()> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let courses: Vec<_> = self
+            .courses
+            .find(doc! { "view": view.cache_id() }, None)
+            .await
+            .map_err(|err| Error::database_while("creating course cursor", err))?
+            .map_ok(|course| course.resource)
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting courses", err))?;
+        let context_codes: Vec<_> = courses
+            .iter()
+            .map(|course| Context::Course(course.id))
+            .collect();
+
+        tracing::debug!("updating announcements for {} courses", context_codes.len());
+
+        // Canvas rejects requests without any context codes
+        if context_codes.is_empty() {
+            replace_cache(
+                &self.db_client,
+                &self.announcements,
+                &view,
+                doc! {},
+                stream::empty(),
+            )
+            .await?;
+
+            return Ok(());
+        }
+
+        let upstream_pages = endpoint::list_announcements(
+            &view.client(self.http.clone()),
+            AnnouncementFilter {
+                context_codes,
+                start_date: courses
+                    .iter()
+                    .map(|course| course.created_at.min(course.start_at))
+                    .min(),
+                end_date: Some(Utc::now()),
+                active_only: true,
+                ..Default::default()
+            },
+        )
+        .pages_owned(100)
+        .map_err(|err| Error::canvas_while("creating announcement pagination stream", err))?
+        .map_err(|err| Error::canvas_while("deserializing announcement response page", err));
+
+        replace_cache(
+            &self.db_client,
+            &self.announcements,
+            &view,
+            doc! {},
+            upstream_pages,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get the view's announcement feed, sorted from newest to oldest.
+    #[oai(
+        path = "/views/:view_id/announcements",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_announcements(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+    ) -> poem::Result<Json<Vec<Any<FeedAnnouncement>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let read: HashSet<Id> = self
+            .announcement_reads
+            .find(doc! { "view": view.cache_id() }, None)
+            .await
+            .map_err(|err| Error::database_while("creating announcement read cursor", err))?
+            .map_ok(|read| read.announcement_id)
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting read announcements", err))?;
+
+        let announcements: Vec<_> = self
+            .announcements
+            .find(
+                doc! { "view": view.cache_id() },
+                FindOptions::builder()
+                    .sort(doc! { "resource.posted_at": -1 })
+                    .build(),
+            )
+            .await
+            .map_err(|err| Error::database_while("creating announcement cursor", err))?
+            .map_ok(|announcement| {
+                Any(FeedAnnouncement {
+                    read: read.contains(&announcement.resource.id),
+                    announcement: announcement.resource,
+                })
+            })
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting announcements into list", err))?;
+
+        Ok(Json(announcements))
+    }
+
+    /// Mark an announcement as read.
+    #[oai(
+        path = "/views/:view_id/announcements/:announcement_id/read",
+        method = "put",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, announcement_id = ?announcement_id.0))]
+    async fn mark_announcement_read(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        announcement_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["write:views"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        self.announcement_reads
+            .update_one(
+                doc! { "view": view.cache_id(), "announcement_id": announcement_id.0 },
+                doc! { "$setOnInsert": { "read_at": bson::DateTime::now() } },
+                UpdateOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map_err(|err| Error::database_while("marking announcement as read", err))?;
+
+        Ok(())
+    }
+
+    /// Mark an announcement as unread.
+    #[oai(
+        path = "/views/:view_id/announcements/:announcement_id/read",
+        method = "delete",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, announcement_id = ?announcement_id.0))]
+    async fn mark_announcement_unread(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        announcement_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["write:views"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        self.announcement_reads
+            .delete_one(
+                doc! { "view": view.cache_id(), "announcement_id": announcement_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("marking announcement as unread", err))?;
+
+        Ok(())
+    }
+
+    /// Get the active global notifications of the view's Canvas account.
+    #[oai(
+        path = "/views/:view_id/account_notifications",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_account_notifications(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+    ) -> poem::Result<Json<Vec<Any<AccountNotification>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let notifications: Vec<_> =
+            notification_endpoint::list_notifications(&view.client(self.http.clone()))
+                .items_owned(100)
+                .map_err(|err| {
+                    Error::canvas_while("creating account notification pagination stream", err)
+                })?
+                .map_ok(Any)
+                .try_collect()
+                .await
+                .map_err(|err| Error::canvas_while("fetching account notifications", err))?;
+
+        Ok(Json(notifications))
+    }
+
+    /// Dismiss a global account notification.
+    #[oai(
+        path = "/views/:view_id/account_notifications/:notification_id",
+        method = "delete",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, notification_id = ?notification_id.0))]
+    async fn close_account_notification(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        notification_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        notification_endpoint::close_notification(
+            &view.client(self.http.clone()),
+            Id::new(notification_id.0 as u64),
+        )
+        .send()
+        .await
+        .map_err(|err| Error::canvas_while("closing account notification", err))?;
+
+        Ok(())
+    }
+}
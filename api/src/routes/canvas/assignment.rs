@@ -1,21 +1,84 @@
-use super::{get_view, DbResource};
+use super::{get_view, DbResource, MAX_CONCURRENT_FETCHES};
 use crate::{Error, HttpClient, auth::Claims, routes::ApiTags, view::*};
 use bson::doc;
-use canvas_lms::resource::Assignment;
+use canvas_lms::{
+    endpoint::{assignment as assignment_endpoint, peer_review as peer_review_endpoint},
+    resource::{Assignment, PeerReview},
+    Client, Id,
+};
 use futures::prelude::*;
 use hyper::client::HttpConnector;
-use hyper::Method;
 use hyper_rustls::HttpsConnector;
 use mongodb::{Collection, Database};
 use poem::error::NotFoundError;
 use poem_openapi::types::Any;
 use poem_openapi::{param::Path, payload::Json, OpenApi};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// An assignment along with the peer reviews the user has yet to complete for it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedAssignment {
+    #[serde(flatten)]
+    pub assignment: Assignment,
+    #[serde(default)]
+    pub has_outstanding_peer_reviews: Option<bool>, // absent if the peer reviews couldn't be fetched
+    #[serde(default)]
+    pub outstanding_peer_reviews: Vec<PeerReview>,
+}
+
+impl CachedAssignment {
+    /// Fetch the peer reviews the view's user has yet to complete for an assignment, if it is peer reviewed.
+    ///
+    /// A failure to fetch them leaves them unknown rather than failing the whole cache update.
+    async fn fetch(
+        client: &Client<HttpsConnector<HttpConnector>>,
+        view: &DbView,
+        assignment: Assignment,
+    ) -> Self {
+        let outstanding_peer_reviews = if assignment.peer_reviews {
+            match Self::fetch_outstanding_reviews(client, view, &assignment).await {
+                Ok(reviews) => Some(reviews),
+                Err(err) => {
+                    tracing::warn!(assignment_id = ?assignment.id, ?err, "failed to fetch peer reviews");
+                    None
+                }
+            }
+        } else {
+            Some(Vec::new())
+        };
+
+        Self {
+            assignment,
+            has_outstanding_peer_reviews: outstanding_peer_reviews
+                .as_ref()
+                .map(|reviews| !reviews.is_empty()),
+            outstanding_peer_reviews: outstanding_peer_reviews.unwrap_or_default(),
+        }
+    }
+
+    async fn fetch_outstanding_reviews(
+        client: &Client<HttpsConnector<HttpConnector>>,
+        view: &DbView,
+        assignment: &Assignment,
+    ) -> Result<Vec<PeerReview>, Error> {
+        let user_id = Id::new(view.student_id());
+        peer_review_endpoint::list_reviews(client, assignment.course_id, assignment.id)
+            .items_owned(100)
+            .map_err(|err| Error::canvas_while("creating peer review pagination stream", err))?
+            .try_filter(|review| {
+                future::ready(review.assessor_id == user_id && review.is_outstanding())
+            })
+            .try_collect()
+            .await
+            .map_err(|err| Error::canvas_while("fetching peer reviews", err))
+    }
+}
+
 pub struct Api {
     db_client: mongodb::Client,
     views: Collection<DbView>,
-    assignments: Collection<DbResource<Assignment>>,
+    assignments: Collection<DbResource<CachedAssignment>>,
 
     http: HttpClient,
 }
@@ -37,7 +100,7 @@ impl Api {
 
 #[OpenApi]
 impl Api {
-    /// Update the assignment cache for a given course.
+    /// Update the assignment cache for a given course as the view's student sees it, along with the peer reviews they have yet to complete.
     #[oai(
         path = "/views/:view_id/courses/:course_id/assignments/update",
         method = "post",
@@ -63,29 +126,32 @@ impl Api {
         })?;
 
         self.assignments
-            .delete_many_with_session(doc! { "view": view.id, "resource.course_id": course_id.0 }, None, &mut session)
+            .delete_many_with_session(doc! { "view": view.cache_id(), "resource.course_id": course_id.0 }, None, &mut session)
             .await
             .map_err(|err| Error::database_while("deleting old cache data", err))?;
         
-        let mut upstream_pages = view
-            .client(self.http.clone())
-            .request(
-                Method::GET,
-                format!("/api/v1/courses/{}/assignments", course_id.0),
-            )
-            .extend_include(["submission", "score_statistics"])
-            .paginate_owned(100)
-            .map_err(|err| Error::canvas_while("creating assignment pagination stream", err))?
-            .and_then(|resp| resp.deserialize::<Vec<Assignment>>().boxed())
-            .map_err(|err| Error::canvas_while("deserializing assignment response page", err));
+        let client = view.client(self.http.clone());
+        let student_id = Id::new(view.student_id());
+        let mut upstream_pages =
+            assignment_endpoint::list_user_assignments(&client, student_id, Id::new(course_id.0 as u64))
+                .extend_include(["submission", "score_statistics"])
+                .pages_owned(100)
+                .map_err(|err| Error::canvas_while("creating assignment pagination stream", err))?
+                .map_err(|err| Error::canvas_while("deserializing assignment response page", err));
 
         // TODO: it would be slightly better to allow each insertion to run concurrently rather than blocking on each one
         let now = bson::DateTime::now();
         while let Some(page) = upstream_pages.next().await.transpose()? {
+            let page: Vec<_> = stream::iter(page)
+                .map(|assignment| CachedAssignment::fetch(&client, &view, assignment))
+                .buffered(MAX_CONCURRENT_FETCHES)
+                .collect()
+                .await;
+
             self.assignments
                 .insert_many_with_session(
                     page.into_iter().map(|resource| DbResource {
-                        view: view.id,
+                        view: view.cache_id(),
                         inserted_at: now,
                         resource,
                     }),
@@ -118,7 +184,7 @@ impl Api {
         claims: Claims,
         view_id: Path<Uuid>,
         course_id: Path<i64>,
-    ) -> poem::Result<Json<Vec<Any<Assignment>>>> {
+    ) -> poem::Result<Json<Vec<Any<CachedAssignment>>>> {
         claims.ensure_scopes(["read:canvas"])?;
 
         let view = get_view(&self.views, view_id.0.into())
@@ -128,7 +194,7 @@ impl Api {
         // TODO: can we avoid the buffering here and start sending immediately?
         let courses: Vec<_> = self
             .assignments
-            .find(doc! { "view": view.id, "resource.course_id": course_id.0 }, None)
+            .find(doc! { "view": view.cache_id(), "resource.course_id": course_id.0 }, None)
             .await
             .map_err(|err| Error::database_while("creating assignment cursor", err))?
             .map_ok(|course| course.resource)
@@ -154,7 +220,7 @@ impl Api {
         view_id: Path<Uuid>,
         course_id: Path<i64>,
         assignment_id: Path<i64>,
-    ) -> poem::Result<Json<Any<Assignment>>> {
+    ) -> poem::Result<Json<Any<CachedAssignment>>> {
         claims.ensure_scopes(["read:canvas"])?;
 
         let view = get_view(&self.views, view_id.0.into())
@@ -163,7 +229,7 @@ impl Api {
 
         let assignment = self
             .assignments
-            .find_one(doc! { "view": view.id, "resource.course_id": course_id.0, "resource.id": assignment_id.0 }, None)
+            .find_one(doc! { "view": view.cache_id(), "resource.course_id": course_id.0, "resource.id": assignment_id.0 }, None)
             .await
             .map_err(|err| Error::database_while("fetching course", err))?
             .ok_or(NotFoundError)?
@@ -0,0 +1,143 @@
+use super::{get_view, replace_cache, CourseScoped, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    endpoint::assignment_group as endpoint,
+    resource::{Assignment, AssignmentGroup},
+    Id,
+};
+use futures::prelude::*;
+use mongodb::{options::FindOptions, Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, OpenApi};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct Api {
+    db_client: mongodb::Client,
+    views: Collection<DbView>,
+    groups: Collection<DbResource<CourseScoped<AssignmentGroup>>>,
+    assignments: Collection<DbResource<Assignment>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            db_client: db_client.clone(),
+            views: database.collection("views"),
+            groups: database.collection("assignment_groups"),
+            assignments: database.collection("assignments"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the assignment group cache for a given course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignment_groups/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn update_assignment_groups(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let canvas_course_id = Id::new(course_id.0 as u64);
+        let upstream_pages =
+            endpoint::list_groups(&view.client(self.http.clone()), canvas_course_id)
+                .pages_owned(100)
+                .map_err(|err| {
+                    Error::canvas_while("creating assignment group pagination stream", err)
+                })?
+                .map_ok(|page| CourseScoped::scope_page(canvas_course_id, page))
+                .map_err(|err| {
+                    Error::canvas_while("deserializing assignment group response page", err)
+                });
+
+        replace_cache(
+            &self.db_client,
+            &self.groups,
+            &view,
+            doc! { "resource.course_id": course_id.0 },
+            upstream_pages,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get a course's assignment groups in order, each with its cached assignments.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignment_groups",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_assignment_groups(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<AssignmentGroup>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let mut assignments = HashMap::<Id, Vec<Assignment>>::new();
+        self.assignments
+            .find(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating assignment cursor", err))?
+            .try_for_each(|assignment| {
+                let assignment = assignment.resource;
+                if let Some(group_id) = assignment.assignment_group_id {
+                    assignments.entry(group_id).or_default().push(assignment);
+                }
+                future::ready(Ok(()))
+            })
+            .await
+            .map_err(|err| Error::database_while("collecting assignments", err))?;
+
+        let groups: Vec<_> = self
+            .groups
+            .find(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                FindOptions::builder()
+                    .sort(doc! { "resource.position": 1 })
+                    .build(),
+            )
+            .await
+            .map_err(|err| Error::database_while("creating assignment group cursor", err))?
+            .map_ok(|group| {
+                let mut group = group.resource.resource;
+                group.assignments = assignments.remove(&group.id).unwrap_or_default();
+                group
+                    .assignments
+                    .sort_by(|a, b| (a.due_at, &a.name).cmp(&(b.due_at, &b.name)));
+                Any(group)
+            })
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting assignment groups into list", err))?;
+
+        Ok(Json(groups))
+    }
+}
@@ -0,0 +1,238 @@
+use super::{get_view, parse_date_param, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    endpoint::{
+        appointment_group as appointment_endpoint,
+        calendar_event::{self as endpoint, CalendarEventFilter, NewReservation},
+    },
+    resource::{AppointmentGroup, Assignment, CalendarEvent, Course},
+    Context, Id,
+};
+use chrono::{DateTime, Duration, Utc};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{
+    param::{Path, Query},
+    payload::Json,
+    types::Any,
+    OpenApi,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// An item on a view's calendar.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CalendarItem {
+    Event(CalendarEvent),
+    Assignment(Assignment),
+}
+
+impl CalendarItem {
+    /// The date by which the item is placed on the calendar.
+    pub fn date(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Event(event) => event.start_at,
+            Self::Assignment(assignment) => assignment.due_at,
+        }
+    }
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+    courses: Collection<DbResource<Course>>,
+    assignments: Collection<DbResource<Assignment>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            courses: database.collection("courses"),
+            assignments: database.collection("assignments"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Get the view's calendar events merged with the due dates of its cached assignments, in chronological order.
+    ///
+    /// Dates are given in RFC 3339 format, and the range defaults to the four weeks starting now.
+    #[oai(
+        path = "/views/:view_id/calendar",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_calendar(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        start_date: Query<Option<String>>,
+        end_date: Query<Option<String>>,
+    ) -> poem::Result<Json<Vec<Any<CalendarItem>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let start_date = parse_date_param("start_date", start_date.0)?.unwrap_or_else(Utc::now);
+        let end_date = parse_date_param("end_date", end_date.0)?
+            .unwrap_or_else(|| start_date + Duration::weeks(4));
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let mut contexts = vec![Context::User(Id::new(view.canvas_user_id))];
+        self.courses
+            .find(doc! { "view": view.cache_id() }, None)
+            .await
+            .map_err(|err| Error::database_while("creating course cursor", err))?
+            .map_ok(|course| Context::Course(course.resource.id))
+            .try_for_each(|context| {
+                contexts.push(context);
+                future::ready(Ok(()))
+            })
+            .await
+            .map_err(|err| Error::database_while("collecting course IDs", err))?;
+
+        let client = view.client(self.http.clone());
+        let mut items = Vec::new();
+        for context_codes in contexts.chunks(endpoint::MAX_CONTEXT_CODES) {
+            endpoint::list_events(
+                &client,
+                CalendarEventFilter {
+                    context_codes: context_codes.to_vec(),
+                    start_date: Some(start_date),
+                    end_date: Some(end_date),
+                    ..Default::default()
+                },
+            )
+            .items_owned(100)
+            .map_err(|err| Error::canvas_while("creating calendar event pagination stream", err))?
+            .map_ok(CalendarItem::Event)
+            .try_for_each(|item| {
+                items.push(item);
+                future::ready(Ok(()))
+            })
+            .await
+            .map_err(|err| Error::canvas_while("fetching calendar events", err))?;
+        }
+
+        self.assignments
+            .find(
+                doc! { "view": view.cache_id(), "resource.due_at": { "$ne": null } },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating assignment cursor", err))?
+            .try_for_each(|assignment| {
+                let assignment = assignment.resource;
+                if matches!(assignment.due_at, Some(due_at) if start_date <= due_at && due_at < end_date)
+                {
+                    items.push(CalendarItem::Assignment(assignment));
+                }
+                future::ready(Ok(()))
+            })
+            .await
+            .map_err(|err| Error::database_while("collecting assignments", err))?;
+
+        items.sort_by_key(CalendarItem::date);
+
+        Ok(Json(items.into_iter().map(Any).collect()))
+    }
+
+    /// Get the appointment groups in which the view can reserve slots.
+    #[oai(
+        path = "/views/:view_id/appointment_groups",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_appointment_groups(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+    ) -> poem::Result<Json<Vec<Any<AppointmentGroup>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let groups: Vec<_> =
+            appointment_endpoint::list_reservable(&view.client(self.http.clone()), false)
+                .items_owned(100)
+                .map_err(|err| {
+                    Error::canvas_while("creating appointment group pagination stream", err)
+                })?
+                .map_ok(Any)
+                .try_collect()
+                .await
+                .map_err(|err| Error::canvas_while("fetching appointment groups", err))?;
+
+        Ok(Json(groups))
+    }
+
+    /// Reserve an appointment slot.
+    #[oai(
+        path = "/views/:view_id/calendar_events/:slot_id/reservations",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, slot_id = ?slot_id.0))]
+    async fn reserve_appointment(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        slot_id: Path<i64>,
+    ) -> poem::Result<Json<Any<CalendarEvent>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let reservation =
+            endpoint::reserve(&view.client(self.http.clone()), Id::new(slot_id.0 as u64))
+                .send_json(&NewReservation::default())
+                .await
+                .map_err(|err| Error::canvas_while("reserving appointment slot", err))?;
+
+        Ok(Json(Any(reservation)))
+    }
+
+    /// Cancel a reservation of an appointment slot.
+    #[oai(
+        path = "/views/:view_id/reservations/:reservation_id",
+        method = "delete",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, reservation_id = ?reservation_id.0))]
+    async fn cancel_reservation(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        reservation_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        endpoint::unreserve(
+            &view.client(self.http.clone()),
+            Id::new(reservation_id.0 as u64),
+        )
+        .send()
+        .await
+        .map_err(|err| Error::canvas_while("cancelling appointment reservation", err))?;
+
+        Ok(())
+    }
+}
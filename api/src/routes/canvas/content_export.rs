@@ -0,0 +1,239 @@
+use super::get_view;
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use canvas_lms::{
+    endpoint::{
+        content_export::{self as endpoint, NewContentExport},
+        progress::get_progress,
+    },
+    resource::{
+        content_export::{ExportType, ExportWorkflowState},
+        ContentExport, Progress,
+    },
+    Id,
+};
+use mongodb::{Collection, Database};
+use poem::{error::NotFoundError, Body};
+use poem_openapi::{
+    param::Path,
+    payload::{Binary, Json},
+    types::Any,
+    ApiResponse, Enum, Object, OpenApi,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The kind of archive to export a course's content as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+#[serde(rename_all = "snake_case")]
+#[oai(rename_all = "snake_case")]
+pub enum NewExportType {
+    /// The whole course
+    CommonCartridge,
+    /// Only the course's quizzes
+    Qti,
+    /// Only the course's files
+    Zip,
+}
+
+impl From<NewExportType> for ExportType {
+    fn from(export_type: NewExportType) -> Self {
+        match export_type {
+            NewExportType::CommonCartridge => Self::CommonCartridge,
+            NewExportType::Qti => Self::Qti,
+            NewExportType::Zip => Self::Zip,
+        }
+    }
+}
+
+/// An export of a course's content to start
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct NewExport {
+    pub export_type: NewExportType,
+}
+
+impl From<NewExport> for NewContentExport {
+    fn from(export: NewExport) -> Self {
+        Self {
+            export_type: export.export_type.into(),
+            skip_notifications: Some(true),
+        }
+    }
+}
+
+/// A content export along with the progress of the job creating its archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportStatus {
+    #[serde(flatten)]
+    pub export: ContentExport,
+    pub progress: Option<Progress>,
+}
+
+#[derive(ApiResponse)]
+enum ArchiveResponse {
+    /// The export's archive
+    #[oai(status = 200)]
+    Ready(Binary<Body>, #[oai(header = "Content-Disposition")] String),
+    /// The export is still being created
+    #[oai(status = 202)]
+    Pending(Json<Any<ExportStatus>>),
+    /// Canvas failed to create the export
+    #[oai(status = 502)]
+    Failed(Json<Any<ExportStatus>>),
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            http,
+        }
+    }
+
+    /// Fetch a content export along with its progress.
+    async fn fetch_status(
+        &self,
+        view: &DbView,
+        course_id: i64,
+        export_id: i64,
+    ) -> poem::Result<ExportStatus> {
+        let client = view.client(self.http.clone());
+
+        let export = endpoint::get_export(
+            &client,
+            Id::new(course_id as u64),
+            Id::new(export_id as u64),
+        )
+        .send()
+        .await
+        .map_err(|err| Error::canvas_while("fetching content export", err))?;
+
+        let progress = match export.progress_id() {
+            Some(progress_id) => Some(
+                get_progress(&client, progress_id)
+                    .send()
+                    .await
+                    .map_err(|err| Error::canvas_while("fetching content export progress", err))?,
+            ),
+            None => None,
+        };
+
+        Ok(ExportStatus { export, progress })
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Start exporting a course's content, such as to keep it after the course concludes.
+    ///
+    /// Poll the export until it is ready, then download its archive.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/exports",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, export), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn create_export(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        export: Json<NewExport>,
+    ) -> poem::Result<Json<Any<ContentExport>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let export =
+            endpoint::create_export(&view.client(self.http.clone()), Id::new(course_id.0 as u64))
+                .send_json(&NewContentExport::from(export.0))
+                .await
+                .map_err(|err| Error::canvas_while("starting content export", err))?;
+
+        Ok(Json(Any(export)))
+    }
+
+    /// Get a content export of a course, along with the progress of its archive.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/exports/:export_id",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, export_id = ?export_id.0))]
+    async fn get_export(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        export_id: Path<i64>,
+    ) -> poem::Result<Json<Any<ExportStatus>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let status = self.fetch_status(&view, course_id.0, export_id.0).await?;
+
+        Ok(Json(Any(status)))
+    }
+
+    /// Download the archive of a content export, streaming it once the export is ready.
+    ///
+    /// Responds with the export's status instead while the archive is still being created.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/exports/:export_id/archive",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, export_id = ?export_id.0))]
+    async fn download_export(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        export_id: Path<i64>,
+    ) -> poem::Result<ArchiveResponse> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let status = self.fetch_status(&view, course_id.0, export_id.0).await?;
+
+        match status.export.workflow_state {
+            ExportWorkflowState::Exported => {}
+            ExportWorkflowState::Failed => return Ok(ArchiveResponse::Failed(Json(Any(status)))),
+            _ => return Ok(ArchiveResponse::Pending(Json(Any(status)))),
+        }
+        // Canvas attaches the archive as soon as the export finishes
+        let attachment = status.export.attachment.as_ref().ok_or(NotFoundError)?;
+
+        let archive = view
+            .client(self.http.clone())
+            .download(&attachment.url)
+            .await
+            .map_err(|err| Error::canvas_while("downloading content export", err))?;
+
+        let filename = attachment.filename.clone().unwrap_or_else(|| {
+            let extension = match status.export.export_type {
+                ExportType::CommonCartridge => "imscc",
+                ExportType::Zip | ExportType::Qti => "zip",
+            };
+            format!("course-{}-export.{}", course_id.0, extension)
+        });
+
+        Ok(ArchiveResponse::Ready(
+            Binary(archive.into_body().into()),
+            format!("attachment; filename=\"{}\"", filename.replace('"', "")),
+        ))
+    }
+}
@@ -0,0 +1,405 @@
+use super::{get_view, replace_cache, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient, Result};
+use bson::doc;
+use canvas_lms::{
+    endpoint::conversation::{
+        self as endpoint, ConversationScope, ConversationUpdate,
+        NewConversation as CanvasNewConversation, NewMessage as CanvasNewMessage,
+    },
+    resource::conversation::{Conversation, ConversationWorkflowState},
+    Context, Id,
+};
+use futures::prelude::*;
+use mongodb::{
+    options::{FindOptions, ReplaceOptions},
+    Collection, Database,
+};
+use poem::error::NotFoundError;
+use poem_openapi::{
+    param::{Path, Query},
+    payload::Json,
+    types::Any,
+    Enum, Object, OpenApi,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A subset of the inbox
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+#[oai(rename_all = "snake_case")]
+pub enum InboxScope {
+    Unread,
+    Starred,
+    Archived,
+}
+
+/// A new conversation to be started by the client
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct NewConversation {
+    /// User IDs, or context codes such as `course_123_students`
+    pub recipients: Vec<String>,
+    pub subject: String,
+    pub body: String,
+    pub context_code: Option<String>,
+    /// Whether all recipients share one conversation rather than each getting their own
+    #[oai(default)]
+    pub group_conversation: bool,
+}
+
+/// A reply to a conversation to be sent by the client
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct NewMessage {
+    pub body: String,
+}
+
+/// The number of unread conversations in a view's inbox
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct UnreadCount {
+    pub unread_count: u64,
+}
+
+pub struct Api {
+    db_client: mongodb::Client,
+    views: Collection<DbView>,
+    conversations: Collection<DbResource<Conversation>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            db_client: db_client.clone(),
+            views: database.collection("views"),
+            conversations: database.collection("conversations"),
+            http,
+        }
+    }
+
+    /// Insert or replace a single conversation in the view's cache.
+    async fn cache_conversation(
+        &self,
+        view: &DbView,
+        mut conversation: Conversation,
+    ) -> Result<()> {
+        // the list endpoint which fills the cache doesn't include messages either
+        conversation.messages.clear();
+
+        self.conversations
+            .replace_one(
+                doc! { "view": view.cache_id(), "resource.id": u64::from(conversation.id) as i64 },
+                DbResource {
+                    view: view.cache_id(),
+                    inserted_at: bson::DateTime::now(),
+                    resource: conversation,
+                },
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map_err(|err| Error::database_while("caching conversation", err))?;
+
+        Ok(())
+    }
+
+    async fn set_cached_workflow_state(
+        &self,
+        view: &DbView,
+        conversation_id: i64,
+        workflow_state: ConversationWorkflowState,
+    ) -> Result<()> {
+        let workflow_state = match workflow_state {
+            ConversationWorkflowState::Read => "read",
+            ConversationWorkflowState::Unread => "unread",
+            ConversationWorkflowState::Archived => "archived",
+        };
+
+        self.conversations
+            .update_one(
+                doc! { "view": view.cache_id(), "resource.id": conversation_id },
+                doc! { "$set": { "resource.workflow_state": workflow_state } },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("updating cached conversation", err))?;
+
+        Ok(())
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the inbox cache, including archived conversations.
+    #[oai(
+        path = "/views/:view_id/conversations/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn update_conversations(&self, claims: Claims, view_id: Path<Uuid>) -> poem::Result<()> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+        let client = view.client(self.http.clone());
+
+        // the default scope excludes archived conversations, so they must be fetched separately
+        let inbox_pages = endpoint::list_conversations(&client, None)
+            .pages_owned(100)
+            .map_err(|err| Error::canvas_while("creating conversation pagination stream", err))?;
+        let archived_pages =
+            endpoint::list_conversations(&client, Some(ConversationScope::Archived))
+                .pages_owned(100)
+                .map_err(|err| {
+                    Error::canvas_while("creating archived conversation pagination stream", err)
+                })?;
+        let upstream_pages = inbox_pages
+            .chain(archived_pages)
+            .map_err(|err| Error::canvas_while("deserializing conversation response page", err));
+
+        replace_cache(
+            &self.db_client,
+            &self.conversations,
+            &view,
+            doc! {},
+            upstream_pages,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get the cached conversations in the inbox, most recently active first.
+    ///
+    /// Archived conversations are only included when `scope` is `archived`.
+    #[oai(
+        path = "/views/:view_id/conversations",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_conversations(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        scope: Query<Option<InboxScope>>,
+    ) -> poem::Result<Json<Vec<Any<Conversation>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let filter = match scope.0 {
+            None => {
+                doc! { "view": view.cache_id(), "resource.workflow_state": { "$ne": "archived" } }
+            }
+            Some(InboxScope::Unread) => {
+                doc! { "view": view.cache_id(), "resource.workflow_state": "unread" }
+            }
+            Some(InboxScope::Starred) => doc! { "view": view.cache_id(), "resource.starred": true },
+            Some(InboxScope::Archived) => {
+                doc! { "view": view.cache_id(), "resource.workflow_state": "archived" }
+            }
+        };
+
+        let conversations: Vec<_> = self
+            .conversations
+            .find(
+                filter,
+                FindOptions::builder()
+                    .sort(doc! { "resource.last_message_at": -1 })
+                    .build(),
+            )
+            .await
+            .map_err(|err| Error::database_while("creating conversation cursor", err))?
+            .map_ok(|conversation| Any(conversation.resource))
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting conversations into list", err))?;
+
+        Ok(Json(conversations))
+    }
+
+    /// Get the number of unread conversations in the cached inbox.
+    #[oai(
+        path = "/views/:view_id/conversations/unread_count",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_unread_count(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+    ) -> poem::Result<Json<UnreadCount>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let unread_count = self
+            .conversations
+            .count_documents(
+                doc! { "view": view.cache_id(), "resource.workflow_state": "unread" },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("counting unread conversations", err))?;
+
+        Ok(Json(UnreadCount { unread_count }))
+    }
+
+    /// Get a conversation along with its messages, without marking it as read.
+    #[oai(
+        path = "/views/:view_id/conversations/:conversation_id",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, conversation_id = ?conversation_id.0))]
+    async fn get_conversation(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        conversation_id: Path<i64>,
+    ) -> poem::Result<Json<Any<Conversation>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let conversation = endpoint::get_conversation(
+            &view.client(self.http.clone()),
+            Id::new(conversation_id.0 as u64),
+            false,
+        )
+        .send()
+        .await
+        .map_err(|err| Error::canvas_while("fetching conversation", err))?;
+
+        Ok(Json(Any(conversation)))
+    }
+
+    /// Mark a conversation as read.
+    #[oai(
+        path = "/views/:view_id/conversations/:conversation_id/read",
+        method = "put",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, conversation_id = ?conversation_id.0))]
+    async fn mark_conversation_read(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        conversation_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        endpoint::update_conversation(
+            &view.client(self.http.clone()),
+            Id::new(conversation_id.0 as u64),
+        )
+        .send_json(&ConversationUpdate::workflow_state(
+            ConversationWorkflowState::Read,
+        ))
+        .await
+        .map_err(|err| Error::canvas_while("marking conversation as read", err))?;
+
+        self.set_cached_workflow_state(&view, conversation_id.0, ConversationWorkflowState::Read)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Start a new conversation, responding with every conversation created.
+    #[oai(
+        path = "/views/:view_id/conversations",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, conversation), fields(view_id = ?view_id.0))]
+    async fn create_conversation(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        conversation: Json<NewConversation>,
+    ) -> poem::Result<Json<Vec<Any<Conversation>>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let NewConversation {
+            recipients,
+            subject,
+            body,
+            context_code,
+            group_conversation,
+        } = conversation.0;
+        let context_code = context_code
+            .map(|code| code.parse::<Context>())
+            .transpose()
+            .map_err(|err| Error::invalid_parameter("context_code", err))?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let conversations = endpoint::create_conversation(&view.client(self.http.clone()))
+            .send_json(&CanvasNewConversation {
+                recipients,
+                subject,
+                body,
+                context_code,
+                group_conversation,
+                attachment_ids: Vec::new(),
+            })
+            .await
+            .map_err(|err| Error::canvas_while("creating conversation", err))?;
+
+        for conversation in &conversations {
+            self.cache_conversation(&view, conversation.clone()).await?;
+        }
+
+        Ok(Json(conversations.into_iter().map(Any).collect()))
+    }
+
+    /// Reply to a conversation, responding with the conversation and the new message.
+    #[oai(
+        path = "/views/:view_id/conversations/:conversation_id/messages",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, message), fields(view_id = ?view_id.0, conversation_id = ?conversation_id.0))]
+    async fn reply_to_conversation(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        conversation_id: Path<i64>,
+        message: Json<NewMessage>,
+    ) -> poem::Result<Json<Any<Conversation>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let conversation = endpoint::add_message(
+            &view.client(self.http.clone()),
+            Id::new(conversation_id.0 as u64),
+        )
+        .send_json(&CanvasNewMessage {
+            body: message.0.body,
+            ..Default::default()
+        })
+        .await
+        .map_err(|err| Error::canvas_while("replying to conversation", err))?;
+
+        self.cache_conversation(&view, conversation.clone()).await?;
+
+        Ok(Json(Any(conversation)))
+    }
+}
@@ -1,21 +1,66 @@
-use super::{get_view, DbResource};
+use super::{get_view, DbResource, MAX_CONCURRENT_FETCHES};
 use crate::{Error, HttpClient, auth::Claims, routes::ApiTags, view::*};
 use bson::doc;
-use canvas_lms::resource::Course;
+use canvas_lms::{
+    endpoint::{grading_standard as grading_standard_endpoint, user as user_endpoint},
+    resource::{Course, GradingStandard},
+    Client, Context, Id,
+};
 use futures::prelude::*;
 use hyper::client::HttpConnector;
-use hyper::Method;
 use hyper_rustls::HttpsConnector;
 use mongodb::{Collection, Database};
 use poem::error::NotFoundError;
 use poem_openapi::types::Any;
 use poem_openapi::{param::Path, payload::Json, OpenApi};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
+/// A course along with the grading standard its letter grades and GPA values are given by.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedCourse {
+    #[serde(flatten)]
+    pub course: Course,
+    #[serde(default)]
+    pub grading_standard: Option<GradingStandard>, // absent if the course uses Canvas's default scheme
+}
+
+impl CachedCourse {
+    /// Fetch a grading standard by way of a course which uses it.
+    ///
+    /// A failure to fetch it falls back to Canvas's default scheme rather than failing the whole cache update.
+    async fn fetch_standard(
+        client: &Client<HttpsConnector<HttpConnector>>,
+        course_id: Id,
+        standard_id: Id,
+    ) -> Option<GradingStandard> {
+        match grading_standard_endpoint::get_standard(
+            client,
+            Context::Course(course_id),
+            standard_id,
+        )
+        .send()
+        .await
+        {
+            Ok(standard) => Some(standard),
+            Err(err) => {
+                tracing::warn!(
+                    ?course_id,
+                    ?standard_id,
+                    ?err,
+                    "failed to fetch grading standard"
+                );
+                None
+            }
+        }
+    }
+}
+
 pub struct Api {
     db_client: mongodb::Client,
     views: Collection<DbView>,
-    courses: Collection<DbResource<Course>>,
+    courses: Collection<DbResource<CachedCourse>>,
 
     http: HttpClient,
 }
@@ -37,7 +82,7 @@ impl Api {
 
 #[OpenApi]
 impl Api {
-    /// Update the course cache.
+    /// Update the course cache for the view's student, along with each course's grading standard.
     #[oai(
         path = "/views/:view_id/courses/update",
         method = "post",
@@ -58,28 +103,54 @@ impl Api {
         })?;
 
         self.courses
-            .delete_many_with_session(doc! { "view": view.id }, None, &mut session)
+            .delete_many_with_session(doc! { "view": view.cache_id() }, None, &mut session)
             .await
             .map_err(|err| Error::database_while("deleting old cache data", err))?;
 
-        let mut upstream_pages = view
-            .client(self.http.clone())
-            .request(Method::GET, "/api/v1/courses")
-            .extend_include(["favorites"])
-            .paginate_owned(100)
+        let client = view.client(self.http.clone());
+        // listing the student's courses rather than our own lets observers see their observee's grades
+        let student_id = Id::new(view.student_id());
+        let mut upstream_pages = user_endpoint::list_user_courses(&client, student_id)
+            .extend_include(["favorites", "term", "total_scores"])
+            .pages_owned(100)
             .map_err(|err| Error::canvas_while("creating course pagination stream", err))?
-            .and_then(|resp| resp.deserialize::<Vec<Course>>().boxed())
             .map_err(|err| Error::canvas_while("deserializing course response page", err));
 
         // TODO: it would be slightly better to allow each insertion to run concurrently rather than blocking on each one
         let now = bson::DateTime::now();
+        let mut standards = HashMap::<Id, Option<GradingStandard>>::new();
         while let Some(page) = upstream_pages.next().await.transpose()? {
+            // courses often share their account's grading standard, so each one is only fetched once
+            let mut unfetched = HashMap::new();
+            for course in &page {
+                if let Some(standard_id) = course.grading_standard_id {
+                    if !standards.contains_key(&standard_id) {
+                        unfetched.entry(standard_id).or_insert(course.id);
+                    }
+                }
+            }
+
+            let fetched: Vec<_> = stream::iter(unfetched)
+                .map(|(standard_id, course_id)| {
+                    CachedCourse::fetch_standard(&client, course_id, standard_id)
+                        .map(move |standard| (standard_id, standard))
+                })
+                .buffer_unordered(MAX_CONCURRENT_FETCHES)
+                .collect()
+                .await;
+            standards.extend(fetched);
+
             self.courses
                 .insert_many_with_session(
                     page.into_iter().map(|course| DbResource {
-                        view: view.id,
+                        view: view.cache_id(),
                         inserted_at: now,
-                        resource: course,
+                        resource: CachedCourse {
+                            grading_standard: course.grading_standard_id.and_then(|standard_id| {
+                                standards.get(&standard_id).cloned().flatten()
+                            }),
+                            course,
+                        },
                     }),
                     None,
                     &mut session,
@@ -107,7 +178,7 @@ impl Api {
         &self,
         claims: Claims,
         view_id: Path<Uuid>,
-    ) -> poem::Result<Json<Vec<Any<Course>>>> {
+    ) -> poem::Result<Json<Vec<Any<CachedCourse>>>> {
         claims.ensure_scopes(["read:canvas"])?;
 
         let view = get_view(&self.views, view_id.0.into())
@@ -117,7 +188,7 @@ impl Api {
         // TODO: can we avoid the buffering here and start sending immediately?
         let courses: Vec<_> = self
             .courses
-            .find(doc! { "view": view.id }, None)
+            .find(doc! { "view": view.cache_id() }, None)
             .await
             .map_err(|err| Error::database_while("creating course cursor", err))?
             .map_ok(|course| course.resource)
@@ -140,7 +211,7 @@ impl Api {
         claims: Claims,
         view_id: Path<Uuid>,
         course_id: Path<i64>,
-    ) -> poem::Result<Json<Any<Course>>> {
+    ) -> poem::Result<Json<Any<CachedCourse>>> {
         claims.ensure_scopes(["read:canvas"])?;
 
         let view = get_view(&self.views, view_id.0.into())
@@ -150,7 +221,7 @@ impl Api {
         let course = self
             .courses
             .find_one(
-                doc! { "view": view.id, "resource.id": course_id.0 },
+                doc! { "view": view.cache_id(), "resource.id": course_id.0 },
                 None,
             )
             .await
@@ -0,0 +1,99 @@
+use super::get_view;
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use canvas_lms::{
+    endpoint::user as endpoint,
+    resource::{ActivityStreamItem, TodoItem, UpcomingEvent},
+};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{
+    param::{Path, Query},
+    payload::Json,
+    types::Any,
+    OpenApi,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The number of activity stream items returned when the client doesn't specify a limit.
+const DEFAULT_ACTIVITY_LIMIT: usize = 20;
+
+/// Everything shown on a view's dashboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dashboard {
+    pub activity_stream: Vec<ActivityStreamItem>,
+    pub todo: Vec<TodoItem>,
+    pub upcoming_events: Vec<UpcomingEvent>,
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Get the view's recent activity, to-do list and upcoming events in a single request.
+    ///
+    /// At most `activity_limit` of the most recent activity stream items are returned, defaulting to 20.
+    #[oai(
+        path = "/views/:view_id/dashboard",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_dashboard(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        activity_limit: Query<Option<u32>>,
+    ) -> poem::Result<Json<Any<Dashboard>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let activity_limit = activity_limit
+            .0
+            .map_or(DEFAULT_ACTIVITY_LIMIT, |limit| limit as usize);
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+        let client = view.client(self.http.clone());
+
+        let activity_stream = endpoint::list_activity_stream(&client, true)
+            .items_owned(activity_limit.clamp(1, 100))
+            .map_err(|err| Error::canvas_while("creating activity stream pagination stream", err))?
+            .take(activity_limit)
+            .try_collect::<Vec<_>>()
+            .map_err(|err| Error::canvas_while("fetching activity stream", err));
+
+        let todo = endpoint::list_todo_items(&client)
+            .items_owned(100)
+            .map_err(|err| Error::canvas_while("creating to-do item pagination stream", err))?
+            .try_collect::<Vec<_>>()
+            .map_err(|err| Error::canvas_while("fetching to-do items", err));
+
+        let upcoming_events = endpoint::list_upcoming_events(&client)
+            .send()
+            .map_err(|err| Error::canvas_while("fetching upcoming events", err));
+
+        let (activity_stream, todo, upcoming_events) =
+            future::try_join3(activity_stream, todo, upcoming_events).await?;
+
+        Ok(Json(Any(Dashboard {
+            activity_stream,
+            todo,
+            upcoming_events,
+        })))
+    }
+}
@@ -0,0 +1,324 @@
+use super::{get_view, replace_cache, CourseScoped, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    endpoint::discussion_topic as endpoint,
+    resource::{
+        discussion_topic::{DiscussionEntry, DiscussionTopic, FullTopicView},
+        Assignment,
+    },
+    Context, Id,
+};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, Object, OpenApi};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A new discussion entry or reply to be posted by the client
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct NewEntry {
+    pub message: String,
+}
+
+/// A discussion topic along with the assignment it is graded by, if any.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicDetails {
+    pub topic: DiscussionTopic,
+    pub assignment: Option<Assignment>,
+}
+
+pub struct Api {
+    db_client: mongodb::Client,
+    views: Collection<DbView>,
+    topics: Collection<DbResource<CourseScoped<DiscussionTopic>>>,
+    assignments: Collection<DbResource<Assignment>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            db_client: db_client.clone(),
+            views: database.collection("views"),
+            topics: database.collection("discussion_topics"),
+            assignments: database.collection("assignments"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the discussion topic cache for a given course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/discussion_topics/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn update_discussion_topics(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let canvas_course_id = Id::new(course_id.0 as u64);
+        let upstream_pages = endpoint::list_topics(
+            &view.client(self.http.clone()),
+            Context::Course(canvas_course_id),
+        )
+        .pages_owned(100)
+        .map_err(|err| Error::canvas_while("creating discussion topic pagination stream", err))?
+        .map_ok(|page| CourseScoped::scope_page(canvas_course_id, page))
+        .map_err(|err| Error::canvas_while("deserializing discussion topic response page", err));
+
+        replace_cache(
+            &self.db_client,
+            &self.topics,
+            &view,
+            doc! { "resource.course_id": course_id.0 },
+            upstream_pages,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get all discussion topics for a course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/discussion_topics",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_discussion_topics(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<DiscussionTopic>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let topics: Vec<_> = self
+            .topics
+            .find(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating discussion topic cursor", err))?
+            .map_ok(|topic| Any(topic.resource.resource))
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting discussion topics into list", err))?;
+
+        Ok(Json(topics))
+    }
+
+    /// Get a discussion topic by its ID, along with its assignment if it is graded.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/discussion_topics/:topic_id",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, topic_id = ?topic_id.0))]
+    async fn get_discussion_topic_by_id(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        topic_id: Path<i64>,
+    ) -> poem::Result<Json<Any<TopicDetails>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let topic = self
+            .topics
+            .find_one(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0, "resource.id": topic_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("fetching discussion topic", err))?
+            .ok_or(NotFoundError)?
+            .resource
+            .resource;
+
+        let assignment = match topic.assignment_id {
+            Some(assignment_id) => self
+                .assignments
+                .find_one(
+                    doc! { "view": view.cache_id(), "resource.id": u64::from(assignment_id) as i64 },
+                    None,
+                )
+                .await
+                .map_err(|err| Error::database_while("fetching discussion topic assignment", err))?
+                .map(|assignment| assignment.resource),
+            None => None,
+        };
+
+        Ok(Json(Any(TopicDetails { topic, assignment })))
+    }
+
+    /// Get the full, threaded view of a discussion topic's entries.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/discussion_topics/:topic_id/view",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, topic_id = ?topic_id.0))]
+    async fn get_discussion_topic_view(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        topic_id: Path<i64>,
+    ) -> poem::Result<Json<Any<FullTopicView>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let full_view = endpoint::get_full_topic(
+            &view.client(self.http.clone()),
+            Context::Course(Id::new(course_id.0 as u64)),
+            Id::new(topic_id.0 as u64),
+        )
+        .send()
+        .await
+        .map_err(|err| Error::canvas_while("fetching full discussion topic view", err))?;
+
+        Ok(Json(Any(full_view)))
+    }
+
+    /// Post a new top-level entry to a discussion topic.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/discussion_topics/:topic_id/entries",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, topic_id = ?topic_id.0))]
+    async fn post_discussion_entry(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        topic_id: Path<i64>,
+        entry: Json<NewEntry>,
+    ) -> poem::Result<Json<Any<DiscussionEntry>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let entry = endpoint::post_entry(
+            &view.client(self.http.clone()),
+            Context::Course(Id::new(course_id.0 as u64)),
+            Id::new(topic_id.0 as u64),
+        )
+        .send_json(&endpoint::NewEntry {
+            message: entry.0.message,
+        })
+        .await
+        .map_err(|err| Error::canvas_while("posting discussion entry", err))?;
+
+        Ok(Json(Any(entry)))
+    }
+
+    /// Reply to a discussion entry.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/discussion_topics/:topic_id/entries/:entry_id/replies",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, topic_id = ?topic_id.0, entry_id = ?entry_id.0))]
+    async fn post_discussion_reply(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        topic_id: Path<i64>,
+        entry_id: Path<i64>,
+        reply: Json<NewEntry>,
+    ) -> poem::Result<Json<Any<DiscussionEntry>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let reply = endpoint::post_reply(
+            &view.client(self.http.clone()),
+            Context::Course(Id::new(course_id.0 as u64)),
+            Id::new(topic_id.0 as u64),
+            Id::new(entry_id.0 as u64),
+        )
+        .send_json(&endpoint::NewEntry {
+            message: reply.0.message,
+        })
+        .await
+        .map_err(|err| Error::canvas_while("posting discussion reply", err))?;
+
+        Ok(Json(Any(reply)))
+    }
+
+    /// Mark a discussion topic and all of its entries as read.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/discussion_topics/:topic_id/read",
+        method = "put",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, topic_id = ?topic_id.0))]
+    async fn mark_discussion_topic_read(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        topic_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        endpoint::mark_all_read(
+            &view.client(self.http.clone()),
+            Context::Course(Id::new(course_id.0 as u64)),
+            Id::new(topic_id.0 as u64),
+        )
+        .execute()
+        .await
+        .map_err(|err| Error::canvas_while("marking discussion topic as read", err))?;
+
+        // keep the cache consistent with Canvas without refetching the whole course
+        self.topics
+            .update_one(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0, "resource.id": topic_id.0 },
+                doc! { "$set": { "resource.read_state": "read", "resource.unread_count": 0 } },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("updating cached discussion topic", err))?;
+
+        Ok(())
+    }
+}
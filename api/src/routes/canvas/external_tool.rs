@@ -0,0 +1,136 @@
+use super::get_view;
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use canvas_lms::{
+    endpoint::external_tool::{self as endpoint, LaunchTarget},
+    resource::{ExternalTool, SessionlessLaunch},
+    Context, Id,
+};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, OpenApi};
+use uuid::Uuid;
+
+pub struct Api {
+    views: Collection<DbView>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            http,
+        }
+    }
+
+    async fn launch(
+        &self,
+        view_id: Uuid,
+        course_id: i64,
+        target: LaunchTarget,
+    ) -> poem::Result<Json<Any<SessionlessLaunch>>> {
+        let view = get_view(&self.views, view_id.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let launch = endpoint::sessionless_launch(
+            &view.client(self.http.clone()),
+            Id::new(course_id as u64),
+            target,
+        )
+        .send()
+        .await
+        .map_err(|err| Error::canvas_while("creating sessionless launch", err))?;
+
+        Ok(Json(Any(launch)))
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Get the external tools which appear in a course's navigation.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/external_tools",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_navigation_tools(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<ExternalTool>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let tools: Vec<_> = endpoint::list_tools(
+            &view.client(self.http.clone()),
+            Context::Course(Id::new(course_id.0 as u64)),
+        )
+        .items_owned(100)
+        .map_err(|err| Error::canvas_while("creating external tool pagination stream", err))?
+        .try_filter(|tool| future::ready(tool.course_navigation.is_some()))
+        .map_ok(Any)
+        .try_collect()
+        .await
+        .map_err(|err| Error::canvas_while("fetching external tools", err))?;
+
+        Ok(Json(tools))
+    }
+
+    /// Get a one-time URL which opens a tool from a course's navigation.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/external_tools/:tool_id/launch",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, tool_id = ?tool_id.0))]
+    async fn launch_navigation_tool(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        tool_id: Path<i64>,
+    ) -> poem::Result<Json<Any<SessionlessLaunch>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let tool_id = Id::new(tool_id.0 as u64);
+        self.launch(
+            view_id.0,
+            course_id.0,
+            LaunchTarget::CourseNavigation { tool_id },
+        )
+        .await
+    }
+
+    /// Get a one-time URL which opens the external tool an assignment is completed in.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/launch",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0))]
+    async fn launch_assignment_tool(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+    ) -> poem::Result<Json<Any<SessionlessLaunch>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let assignment_id = Id::new(assignment_id.0 as u64);
+        self.launch(
+            view_id.0,
+            course_id.0,
+            LaunchTarget::Assessment { assignment_id },
+        )
+        .await
+    }
+}
@@ -0,0 +1,298 @@
+use super::{course::CachedCourse, get_view, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::{doc, Document};
+use canvas_lms::{
+    endpoint::{
+        favorite as endpoint,
+        user::{self as user_endpoint, NewCourseNickname},
+    },
+    resource::Favorite,
+    Id,
+};
+use mongodb::{
+    options::{FindOneAndUpdateOptions, ReturnDocument},
+    Collection, Database,
+};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, ApiResponse, Object, OpenApi};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A nickname to show in place of a course's name
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct NewNickname {
+    /// At most 59 characters long
+    pub nickname: String,
+}
+
+#[derive(ApiResponse)]
+enum CourseResponse {
+    /// The cached course, updated to match Canvas
+    #[oai(status = 200)]
+    Updated(Json<Any<CachedCourse>>),
+    /// Canvas was updated, but the course isn't cached
+    #[oai(status = 204)]
+    NotCached,
+}
+
+impl From<Option<CachedCourse>> for CourseResponse {
+    fn from(course: Option<CachedCourse>) -> Self {
+        course.map_or(Self::NotCached, |course| Self::Updated(Json(Any(course))))
+    }
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+    courses: Collection<DbResource<CachedCourse>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            courses: database.collection("courses"),
+            http,
+        }
+    }
+
+    /// Apply `update` to a course in the user's own cache in place, returning the updated course if it's cached.
+    ///
+    /// Favorites and nicknames belong to the user rather than any observee they're viewing as.
+    async fn patch_cached_course(
+        &self,
+        view: &DbView,
+        course_id: i64,
+        update: Document,
+    ) -> poem::Result<Option<CachedCourse>> {
+        Ok(self
+            .courses
+            .find_one_and_update(
+                doc! { "view": view.id, "resource.id": course_id },
+                update,
+                FindOneAndUpdateOptions::builder()
+                    .return_document(ReturnDocument::After)
+                    .build(),
+            )
+            .await
+            .map_err(|err| Error::database_while("updating cached course", err))?
+            .map(|course| course.resource))
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Add a course to the user's favorites, updating its cached favorite status.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/favorite",
+        method = "put",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn favorite_course(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<CourseResponse> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        endpoint::favorite_course(&view.client(self.http.clone()), Id::new(course_id.0 as u64))
+            .send()
+            .await
+            .map_err(|err| Error::canvas_while("favoriting course", err))?;
+
+        let course = self
+            .patch_cached_course(
+                &view,
+                course_id.0,
+                doc! { "$set": { "resource.is_favorite": true } },
+            )
+            .await?;
+
+        Ok(course.into())
+    }
+
+    /// Remove a course from the user's favorites, updating its cached favorite status.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/favorite",
+        method = "delete",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn unfavorite_course(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<CourseResponse> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        endpoint::unfavorite_course(&view.client(self.http.clone()), Id::new(course_id.0 as u64))
+            .send()
+            .await
+            .map_err(|err| Error::canvas_while("unfavoriting course", err))?;
+
+        let course = self
+            .patch_cached_course(
+                &view,
+                course_id.0,
+                doc! { "$set": { "resource.is_favorite": false } },
+            )
+            .await?;
+
+        Ok(course.into())
+    }
+
+    /// Add a group to the user's favorites.
+    #[oai(
+        path = "/views/:view_id/groups/:group_id/favorite",
+        method = "put",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, group_id = ?group_id.0))]
+    async fn favorite_group(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        group_id: Path<i64>,
+    ) -> poem::Result<Json<Any<Favorite>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let favorite =
+            endpoint::favorite_group(&view.client(self.http.clone()), Id::new(group_id.0 as u64))
+                .send()
+                .await
+                .map_err(|err| Error::canvas_while("favoriting group", err))?;
+
+        Ok(Json(Any(favorite)))
+    }
+
+    /// Remove a group from the user's favorites.
+    #[oai(
+        path = "/views/:view_id/groups/:group_id/favorite",
+        method = "delete",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, group_id = ?group_id.0))]
+    async fn unfavorite_group(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        group_id: Path<i64>,
+    ) -> poem::Result<Json<Any<Favorite>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let favorite =
+            endpoint::unfavorite_group(&view.client(self.http.clone()), Id::new(group_id.0 as u64))
+                .send()
+                .await
+                .map_err(|err| Error::canvas_while("unfavoriting group", err))?;
+
+        Ok(Json(Any(favorite)))
+    }
+
+    /// Set the user's nickname for a course, updating its cached name.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/nickname",
+        method = "put",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, nickname), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn set_course_nickname(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        nickname: Json<NewNickname>,
+    ) -> poem::Result<CourseResponse> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let nickname = user_endpoint::set_course_nickname(
+            &view.client(self.http.clone()),
+            Id::new(course_id.0 as u64),
+        )
+        .send_json(&NewCourseNickname {
+            nickname: nickname.0.nickname,
+        })
+        .await
+        .map_err(|err| Error::canvas_while("setting course nickname", err))?;
+
+        let course = self
+            .patch_cached_course(
+                &view,
+                course_id.0,
+                doc! {
+                    "$set": {
+                        "resource.name": nickname.nickname.unwrap_or_else(|| nickname.name.clone()),
+                        "resource.original_name": nickname.name,
+                    }
+                },
+            )
+            .await?;
+
+        Ok(course.into())
+    }
+
+    /// Remove the user's nickname for a course, restoring its cached name.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/nickname",
+        method = "delete",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn remove_course_nickname(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<CourseResponse> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let nickname = user_endpoint::remove_course_nickname(
+            &view.client(self.http.clone()),
+            Id::new(course_id.0 as u64),
+        )
+        .send()
+        .await
+        .map_err(|err| Error::canvas_while("removing course nickname", err))?;
+
+        let course = self
+            .patch_cached_course(
+                &view,
+                course_id.0,
+                doc! {
+                    "$set": { "resource.name": nickname.name },
+                    "$unset": { "resource.original_name": "" },
+                },
+            )
+            .await?;
+
+        Ok(course.into())
+    }
+}
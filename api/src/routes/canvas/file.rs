@@ -0,0 +1,202 @@
+use super::{
+    commit_cache_transaction, get_view, replace_cache_with_session, start_cache_transaction,
+    CourseScoped, DbResource,
+};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    endpoint::file as endpoint,
+    resource::file::{File, Folder, FolderTree},
+    Context, Id,
+};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, OpenApi};
+use uuid::Uuid;
+
+pub struct Api {
+    db_client: mongodb::Client,
+    views: Collection<DbView>,
+    folders: Collection<DbResource<CourseScoped<Folder>>>,
+    files: Collection<DbResource<CourseScoped<File>>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            db_client: db_client.clone(),
+            views: database.collection("views"),
+            folders: database.collection("folders"),
+            files: database.collection("files"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the file and folder cache for a given course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/files/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn update_files(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+        let client = view.client(self.http.clone());
+        let canvas_course_id = Id::new(course_id.0 as u64);
+
+        let folder_pages = endpoint::list_folders(&client, Context::Course(canvas_course_id))
+            .pages_owned(100)
+            .map_err(|err| Error::canvas_while("creating folder pagination stream", err))?
+            .map_ok(|page| CourseScoped::scope_page(canvas_course_id, page))
+            .map_err(|err| Error::canvas_while("deserializing folder response page", err));
+
+        let file_pages = endpoint::list_files(&client, Context::Course(canvas_course_id))
+            .pages_owned(100)
+            .map_err(|err| Error::canvas_while("creating file pagination stream", err))?
+            .map_ok(|page| CourseScoped::scope_page(canvas_course_id, page))
+            .map_err(|err| Error::canvas_while("deserializing file response page", err));
+
+        // folders and files are replaced together so cached files never refer to folders which are gone
+        let filter = doc! { "resource.course_id": course_id.0 };
+        let mut session = start_cache_transaction(&self.db_client).await?;
+        replace_cache_with_session(
+            &self.folders,
+            &view,
+            filter.clone(),
+            folder_pages,
+            &mut session,
+        )
+        .await?;
+        replace_cache_with_session(&self.files, &view, filter, file_pages, &mut session).await?;
+        commit_cache_transaction(session).await?;
+
+        Ok(())
+    }
+
+    /// Get the folder hierarchy of a course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/folders",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_folders(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<FolderTree>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let folders: Vec<_> = self
+            .folders
+            .find(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating folder cursor", err))?
+            .map_ok(|folder| folder.resource.resource)
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting folders into list", err))?;
+
+        Ok(Json(
+            FolderTree::build(folders).into_iter().map(Any).collect(),
+        ))
+    }
+
+    /// Get the files directly inside a folder.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/folders/:folder_id/files",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, folder_id = ?folder_id.0))]
+    async fn get_folder_files(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        folder_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<File>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let files: Vec<_> = self
+            .files
+            .find(
+                doc! {
+                    "view": view.cache_id(),
+                    "resource.course_id": course_id.0,
+                    "resource.folder_id": folder_id.0,
+                },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating file cursor", err))?
+            .map_ok(|file| Any(file.resource.resource))
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting files into list", err))?;
+
+        Ok(Json(files))
+    }
+
+    /// Get a file's metadata by its ID.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/files/:file_id",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, file_id = ?file_id.0))]
+    async fn get_file_by_id(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        file_id: Path<i64>,
+    ) -> poem::Result<Json<Any<File>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let file = self
+            .files
+            .find_one(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0, "resource.id": file_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("fetching file", err))?
+            .ok_or(NotFoundError)?
+            .resource
+            .resource;
+
+        Ok(Json(Any(file)))
+    }
+}
@@ -0,0 +1,244 @@
+use super::get_view;
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use canvas_lms::{
+    endpoint::{
+        progress::get_progress,
+        submission::{
+            self as endpoint, BulkGradeUpdate, GradeData, GradeUpdate, NewSubmissionComment,
+            SubmissionUpdate,
+        },
+    },
+    resource::{Progress, RubricAssessment, Submission},
+    Id,
+};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, Object, OpenApi};
+use uuid::Uuid;
+
+/// A grade, comment or rubric assessment to give a student's submission, leaving any unset fields untouched
+#[derive(Debug, Clone, Object)]
+pub struct NewGrade {
+    /// A score such as `87`, a percentage such as `87%`, a letter grade or `pass`, depending on the assignment's grading type
+    pub posted_grade: Option<String>,
+    /// Excuse the student from the assignment, or stop excusing them
+    pub excuse: Option<bool>,
+    pub comment: Option<String>,
+    /// Replaces the submission's rubric assessment, keyed by criterion ID
+    pub rubric_assessment: Option<Any<RubricAssessment>>,
+}
+
+impl From<NewGrade> for SubmissionUpdate {
+    fn from(grade: NewGrade) -> Self {
+        Self {
+            comment: grade.comment.map(|text_comment| NewSubmissionComment {
+                text_comment,
+                ..Default::default()
+            }),
+            submission: (grade.posted_grade.is_some() || grade.excuse.is_some()).then_some(
+                GradeUpdate {
+                    posted_grade: grade.posted_grade,
+                    excuse: grade.excuse,
+                },
+            ),
+            rubric_assessment: grade.rubric_assessment.map(|assessment| assessment.0),
+        }
+    }
+}
+
+/// Grades for many students' submissions at once
+#[derive(Debug, Clone, Object)]
+pub struct NewGrades {
+    pub grades: Vec<StudentGrade>,
+}
+
+/// A grade, comment or rubric assessment to give a single student's submission
+#[derive(Debug, Clone, Object)]
+pub struct StudentGrade {
+    /// The student's Canvas ID
+    pub user_id: u64,
+    /// As in a single grade update
+    pub posted_grade: Option<String>,
+    pub excuse: Option<bool>,
+    pub comment: Option<String>,
+    pub rubric_assessment: Option<Any<RubricAssessment>>,
+}
+
+impl From<NewGrades> for BulkGradeUpdate {
+    fn from(grades: NewGrades) -> Self {
+        Self {
+            grade_data: grades
+                .grades
+                .into_iter()
+                .map(|grade| {
+                    (
+                        Id::new(grade.user_id),
+                        GradeData {
+                            posted_grade: grade.posted_grade,
+                            excuse: grade.excuse,
+                            text_comment: grade.comment,
+                            rubric_assessment: grade
+                                .rubric_assessment
+                                .map(|assessment| assessment.0),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Get every student's submission for an assignment along with the student, which requires the user to grade it.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/submissions",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0))]
+    async fn get_assignment_submissions(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<Submission>>>> {
+        claims.ensure_scopes(["read:canvas", "write:grades"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let submissions = endpoint::list_submissions(
+            &view.client(self.http.clone()),
+            Id::new(course_id.0 as u64),
+            Id::new(assignment_id.0 as u64),
+        )
+        .extend_include(["user", "rubric_assessment", "submission_comments"])
+        .items_owned(100)
+        .map_err(|err| Error::canvas_while("creating submission pagination stream", err))?
+        .map_ok(Any)
+        .try_collect()
+        .await
+        .map_err(|err| Error::canvas_while("fetching submissions", err))?;
+
+        Ok(Json(submissions))
+    }
+
+    /// Grade, excuse, assess or comment on a student's submission for an assignment.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/submissions/:user_id",
+        method = "put",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, grade), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0, user_id = ?user_id.0))]
+    async fn grade_submission(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+        user_id: Path<i64>,
+        grade: Json<NewGrade>,
+    ) -> poem::Result<Json<Any<Submission>>> {
+        claims.ensure_scopes(["write:grades"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let submission = endpoint::update_submission(
+            &view.client(self.http.clone()),
+            Id::new(course_id.0 as u64),
+            Id::new(assignment_id.0 as u64),
+            Id::new(user_id.0 as u64),
+        )
+        .send_json(&SubmissionUpdate::from(grade.0))
+        .await
+        .map_err(|err| Error::canvas_while("grading submission", err))?;
+
+        Ok(Json(Any(submission)))
+    }
+
+    /// Start grading many students' submissions for an assignment at once.
+    ///
+    /// Canvas applies the grades in the background, so poll the returned progress until it completes.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/submissions/grades",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, grades), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0))]
+    async fn grade_submissions(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+        grades: Json<NewGrades>,
+    ) -> poem::Result<Json<Any<Progress>>> {
+        claims.ensure_scopes(["write:grades"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let progress = endpoint::update_grades(
+            &view.client(self.http.clone()),
+            Id::new(course_id.0 as u64),
+            Id::new(assignment_id.0 as u64),
+        )
+        .send_json(&BulkGradeUpdate::from(grades.0))
+        .await
+        .map_err(|err| Error::canvas_while("starting bulk grade update", err))?;
+
+        Ok(Json(Any(progress)))
+    }
+
+    /// Get the progress of a job running in the background, such as a bulk grade update.
+    #[oai(
+        path = "/views/:view_id/progress/:progress_id",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, progress_id = ?progress_id.0))]
+    async fn get_progress(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        progress_id: Path<i64>,
+    ) -> poem::Result<Json<Any<Progress>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let progress = get_progress(
+            &view.client(self.http.clone()),
+            Id::new(progress_id.0 as u64),
+        )
+        .send()
+        .await
+        .map_err(|err| Error::canvas_while("fetching progress", err))?;
+
+        Ok(Json(Any(progress)))
+    }
+}
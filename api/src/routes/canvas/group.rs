@@ -0,0 +1,126 @@
+use super::{get_view, replace_cache, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{endpoint::group as endpoint, resource::Group};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, OpenApi};
+use uuid::Uuid;
+
+pub struct Api {
+    db_client: mongodb::Client,
+    views: Collection<DbView>,
+    groups: Collection<DbResource<Group>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            db_client: db_client.clone(),
+            views: database.collection("views"),
+            groups: database.collection("groups"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the cache of groups the view's user belongs to.
+    #[oai(
+        path = "/views/:view_id/groups/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn update_groups(&self, claims: Claims, view_id: Path<Uuid>) -> poem::Result<()> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let upstream_pages = endpoint::list_own_groups(&view.client(self.http.clone()))
+            .pages_owned(100)
+            .map_err(|err| Error::canvas_while("creating group pagination stream", err))?
+            .map_err(|err| Error::canvas_while("deserializing group response page", err));
+
+        replace_cache(
+            &self.db_client,
+            &self.groups,
+            &view,
+            doc! {},
+            upstream_pages,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get all groups the view's user belongs to.
+    #[oai(
+        path = "/views/:view_id/groups",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_groups(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+    ) -> poem::Result<Json<Vec<Any<Group>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let groups: Vec<_> = self
+            .groups
+            .find(doc! { "view": view.cache_id() }, None)
+            .await
+            .map_err(|err| Error::database_while("creating group cursor", err))?
+            .map_ok(|group| Any(group.resource))
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting groups into list", err))?;
+
+        Ok(Json(groups))
+    }
+
+    /// Get a group by its ID.
+    #[oai(
+        path = "/views/:view_id/groups/:group_id",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, group_id = ?group_id.0))]
+    async fn get_group_by_id(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        group_id: Path<i64>,
+    ) -> poem::Result<Json<Any<Group>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let group = self
+            .groups
+            .find_one(
+                doc! { "view": view.cache_id(), "resource.id": group_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("fetching group", err))?
+            .ok_or(NotFoundError)?
+            .resource;
+
+        Ok(Json(Any(group)))
+    }
+}
@@ -0,0 +1,218 @@
+use super::{assignment::CachedAssignment, get_view, CourseScoped, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    endpoint::late_policy as endpoint,
+    resource::{
+        late_policy::DeductionPrediction, submission::SubmissionType, Assignment, LatePolicy,
+        Submission,
+    },
+    Id,
+};
+use chrono::Utc;
+use futures::prelude::*;
+use mongodb::{options::ReplaceOptions, Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, OpenApi};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// An assignment which is past due and which the user has yet to submit, along with the deduction they face.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverdueAssignment {
+    #[serde(flatten)]
+    pub assignment: Assignment,
+    pub late_deduction: DeductionPrediction,
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+    late_policies: Collection<DbResource<LatePolicy>>,
+    assignments: Collection<DbResource<CachedAssignment>>,
+    submissions: Collection<DbResource<CourseScoped<Submission>>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            late_policies: database.collection("late_policies"),
+            assignments: database.collection("assignments"),
+            submissions: database.collection("submissions"),
+            http,
+        }
+    }
+
+    async fn find_late_policy(&self, view: &DbView, course_id: i64) -> poem::Result<LatePolicy> {
+        Ok(self
+            .late_policies
+            .find_one(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("fetching late policy", err))?
+            .ok_or(NotFoundError)?
+            .resource)
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the cached late policy of a given course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/late_policy/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn update_late_policy(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let late_policy =
+            endpoint::get_late_policy(&view.client(self.http.clone()), Id::new(course_id.0 as u64))
+                .send()
+                .await
+                .map_err(|err| Error::canvas_while("fetching late policy", err))?
+                .late_policy;
+
+        self.late_policies
+            .replace_one(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                DbResource {
+                    view: view.cache_id(),
+                    inserted_at: bson::DateTime::now(),
+                    resource: late_policy,
+                },
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map_err(|err| Error::database_while("caching late policy", err))?;
+
+        Ok(())
+    }
+
+    /// Get the late policy of a course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/late_policy",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_late_policy(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Any<LatePolicy>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let late_policy = self.find_late_policy(&view, course_id.0).await?;
+
+        Ok(Json(Any(late_policy)))
+    }
+
+    /// Get the assignments in a course which are past due and which the user can still submit but hasn't,
+    /// along with the deduction they face by submitting now or later, soonest due first.
+    ///
+    /// Assignments and submissions are taken from their caches, so both should be updated first.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/overdue",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_overdue_assignments(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<OverdueAssignment>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let late_policy = self.find_late_policy(&view, course_id.0).await?;
+
+        let submitted: HashSet<Id> = self
+            .submissions
+            .find(
+                doc! {
+                    "view": view.cache_id(),
+                    "resource.course_id": course_id.0,
+                    "resource.submitted_at": { "$ne": null },
+                },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating submission cursor", err))?
+            .map_ok(|submission| submission.resource.resource.assignment_id)
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting submissions into set", err))?;
+
+        let now = Utc::now();
+        let mut overdue: Vec<_> = self
+            .assignments
+            .find(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating assignment cursor", err))?
+            .map_ok(|assignment| assignment.resource.assignment)
+            .try_filter_map(|assignment| {
+                let late_deduction = match assignment.due_at {
+                    Some(due_at)
+                        if due_at < now
+                            && !assignment.locked_for_user
+                            && is_submittable(&assignment)
+                            && !submitted.contains(&assignment.id) =>
+                    {
+                        Some(late_policy.predict(due_at, now))
+                    }
+                    _ => None,
+                };
+
+                future::ok(late_deduction.map(|late_deduction| OverdueAssignment {
+                    assignment,
+                    late_deduction,
+                }))
+            })
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting assignments into list", err))?;
+
+        overdue.sort_by_key(|overdue| overdue.assignment.due_at);
+
+        Ok(Json(overdue.into_iter().map(Any).collect()))
+    }
+}
+
+/// Whether an assignment is submitted through Canvas, rather than on paper or not at all.
+fn is_submittable(assignment: &Assignment) -> bool {
+    assignment.submission_types.iter().any(|submission_type| {
+        !matches!(
+            submission_type,
+            SubmissionType::OnPaper | SubmissionType::None | SubmissionType::NotGraded
+        )
+    })
+}
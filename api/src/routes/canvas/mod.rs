@@ -1,16 +1,65 @@
 use crate::{view::DbView, Error, HttpClient, Result};
+use canvas_lms::Id;
+use chrono::{DateTime, Utc};
+use futures::prelude::*;
+use mongodb::ClientSession;
 use serde::{Deserialize, Serialize};
 
+pub mod analytics;
+pub mod announcement;
 pub mod assignment;
+pub mod assignment_group;
+pub mod calendar;
+pub mod content_export;
+pub mod conversation;
 pub mod course;
+pub mod dashboard;
+pub mod discussion;
+pub mod external_tool;
+pub mod favorite;
+pub mod file;
+pub mod grading;
+pub mod group;
+pub mod late_policy;
+pub mod observee;
+pub mod outcome;
+pub mod peer_review;
+pub mod people;
+pub mod planner;
+pub mod profile;
+pub mod quiz;
+pub mod submission;
+pub mod term;
+
+/// The most requests to make to Canvas at once for the extra data cached alongside each page of resources.
+const MAX_CONCURRENT_FETCHES: usize = 8;
 
 #[derive(Debug, Clone, Hash, Serialize, Deserialize)]
 struct DbResource<R> {
-    view: bson::Uuid,
+    view: bson::Uuid, // the view's cache ID, which differs for each observee it is switched to
     inserted_at: bson::DateTime,
     resource: R,
 }
 
+/// A resource which Canvas returns without the ID of the course it belongs to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CourseScoped<R> {
+    course_id: Id,
+    #[serde(flatten)]
+    resource: R,
+}
+
+impl<R> CourseScoped<R> {
+    fn scope_page(course_id: Id, page: Vec<R>) -> Vec<Self> {
+        page.into_iter()
+            .map(|resource| Self {
+                course_id,
+                resource,
+            })
+            .collect()
+    }
+}
+
 // TODO: can we refactor this into a struct implementing `FromRequest` perhaps?
 async fn get_view(
     views: &mongodb::Collection<DbView>,
@@ -22,15 +71,140 @@ async fn get_view(
         .map_err(|err| Error::database_while("fetching view information", err))
 }
 
+/// Parse an optional RFC 3339 date given as a query parameter.
+fn parse_date_param(name: &'static str, value: Option<String>) -> Result<Option<DateTime<Utc>>> {
+    value
+        .map(|value| {
+            DateTime::parse_from_rfc3339(&value)
+                .map(|date| date.with_timezone(&Utc))
+                .map_err(|err| Error::invalid_parameter(name, err))
+        })
+        .transpose()
+}
+
+/// Atomically replace the view's cached resources matching `filter` with the resources yielded by `pages`.
+async fn replace_cache<R, S>(
+    db_client: &mongodb::Client,
+    collection: &mongodb::Collection<DbResource<R>>,
+    view: &DbView,
+    filter: bson::Document,
+    pages: S,
+) -> Result<()>
+where
+    R: Serialize,
+    S: Stream<Item = Result<Vec<R>>> + Unpin,
+{
+    let mut session = start_cache_transaction(db_client).await?;
+    replace_cache_with_session(collection, view, filter, pages, &mut session).await?;
+    commit_cache_transaction(session).await
+}
+
+/// Start a transaction for replacing several caches at once with [`replace_cache_with_session`].
+async fn start_cache_transaction(db_client: &mongodb::Client) -> Result<ClientSession> {
+    let mut session = db_client
+        .start_session(None)
+        .await
+        .map_err(|err| Error::database_while("creating session for atomic cache update", err))?;
+
+    session.start_transaction(None).await.map_err(|err| {
+        Error::database_while("starting transaction for atomic cache update", err)
+    })?;
+
+    Ok(session)
+}
+
+/// Commit a transaction started with [`start_cache_transaction`].
+async fn commit_cache_transaction(mut session: ClientSession) -> Result<()> {
+    session
+        .commit_transaction()
+        .await
+        .map_err(|err| Error::database_while("commiting cache update transaction", err))
+}
+
+/// Replace the view's cached resources matching `filter` with the resources yielded by `pages` as part of a transaction.
+async fn replace_cache_with_session<R, S>(
+    collection: &mongodb::Collection<DbResource<R>>,
+    view: &DbView,
+    mut filter: bson::Document,
+    mut pages: S,
+    session: &mut ClientSession,
+) -> Result<()>
+where
+    R: Serialize,
+    S: Stream<Item = Result<Vec<R>>> + Unpin,
+{
+    filter.insert("view", view.cache_id());
+
+    collection
+        .delete_many_with_session(filter, None, session)
+        .await
+        .map_err(|err| Error::database_while("deleting old cache data", err))?;
+
+    // TODO: it would be slightly better to allow each insertion to run concurrently rather than blocking on each one
+    let now = bson::DateTime::now();
+    while let Some(page) = pages.next().await.transpose()? {
+        // MongoDB refuses to insert an empty batch
+        if page.is_empty() {
+            continue;
+        }
+
+        collection
+            .insert_many_with_session(
+                page.into_iter().map(|resource| DbResource {
+                    view: view.cache_id(),
+                    inserted_at: now,
+                    resource,
+                }),
+                None,
+                session,
+            )
+            .await
+            .map_err(|err| Error::database_while("inserting resources into the cache", err))?;
+    }
+
+    Ok(())
+}
+
 macro_rules! composite_api {
-    ($( $api:ty ),* $(,)?) => {
-        // NOTE: we can remove the unit once poem-rs/poem#232 is merged
-        type Api = ( $($api),*, );
+    ($( [ $( $api:ty ),* $(,)? ] ),* $(,)?) => {
+        // NOTE: we can remove the units once poem-rs/poem#232 is merged
+        // NOTE: poem-openapi only implements `OpenApi` for tuples of up to 16 APIs, so they are nested in groups
+        type Api = ( $( ( $($api),*, ) ),*, );
 
         pub fn make_api(database: &mongodb::Database, db_client: &mongodb::Client, http: &HttpClient) -> Api {
-            ( $( <$api>::new(database, db_client, http.clone()) ),*, )
+            ( $( ( $( <$api>::new(database, db_client, http.clone()) ),*, ) ),*, )
         }
     };
 }
 
-composite_api!(course::Api, assignment::Api);
+composite_api!(
+    [
+        course::Api,
+        assignment::Api,
+        discussion::Api,
+        announcement::Api,
+        quiz::Api,
+        file::Api,
+        calendar::Api,
+        planner::Api,
+        conversation::Api,
+        assignment_group::Api,
+        submission::Api,
+        group::Api,
+        people::Api,
+        profile::Api,
+        dashboard::Api,
+        outcome::Api,
+    ],
+    [
+        external_tool::Api,
+        peer_review::Api,
+        analytics::Api,
+        term::Api,
+        late_policy::Api,
+        content_export::Api,
+        favorite::Api,
+        observee::Api,
+        grading::Api,
+    ],
+);
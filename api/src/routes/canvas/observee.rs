@@ -0,0 +1,178 @@
+use super::get_view;
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::endpoint::user as endpoint;
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, Object, OpenApi};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A student observed by the view's user
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct Observee {
+    /// The student's Canvas ID
+    pub user_id: u64,
+    pub name: String,
+}
+
+/// The observed student for a view to show
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct SwitchObservee {
+    /// The student's Canvas ID, or none to show the user's own resources
+    pub observee_id: Option<u64>,
+}
+
+impl From<&DbObservee> for Observee {
+    fn from(observee: &DbObservee) -> Self {
+        Self {
+            user_id: observee.user_id,
+            name: observee.name.clone(),
+        }
+    }
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            http,
+        }
+    }
+
+    async fn save_view(&self, view: &DbView) -> poem::Result<()> {
+        self.views
+            .replace_one(doc! { "_id": view.id }, view, None)
+            .await
+            .map_err(|err| Error::database_while("updating view", err))?;
+
+        Ok(())
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the students observed by the view's user, such as a parent's children.
+    ///
+    /// If the view is switched to a student who is no longer observed, it is switched back to the user's own resources.
+    #[oai(
+        path = "/views/:view_id/observees/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn update_observees(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+    ) -> poem::Result<Json<Vec<Observee>>> {
+        claims.ensure_scopes(["read:canvas", "write:views"])?;
+
+        let mut view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let observees: Vec<_> = endpoint::list_observees(&view.client(self.http.clone()))
+            .items_owned(100)
+            .map_err(|err| Error::canvas_while("creating observee pagination stream", err))?
+            .try_collect()
+            .await
+            .map_err(|err| Error::canvas_while("fetching observees", err))?;
+
+        // keep the cache IDs of students who were already observed so their caches survive
+        view.observees = observees
+            .into_iter()
+            .map(|user| {
+                let user_id = u64::from(user.id);
+                DbObservee {
+                    user_id,
+                    name: user.name,
+                    cache_id: view
+                        .observees
+                        .iter()
+                        .find(|observee| observee.user_id == user_id)
+                        .map_or_else(|| Uuid::new_v4().into(), |observee| observee.cache_id),
+                }
+            })
+            .collect();
+
+        if let Some(observee_id) = view.observee_id {
+            if !view
+                .observees
+                .iter()
+                .any(|observee| observee.user_id == observee_id)
+            {
+                view.observee_id = None;
+            }
+        }
+
+        self.save_view(&view).await?;
+
+        Ok(Json(view.observees.iter().map(Observee::from).collect()))
+    }
+
+    /// Get the students observed by the view's user.
+    #[oai(
+        path = "/views/:view_id/observees",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_observees(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+    ) -> poem::Result<Json<Vec<Observee>>> {
+        claims.ensure_scopes(["read:views"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        Ok(Json(view.observees.iter().map(Observee::from).collect()))
+    }
+
+    /// Switch the view to show an observed student's courses, assignments, submissions and grades, or back to the user's own.
+    ///
+    /// Each student's resources are cached separately, so caches should be updated after switching to a student for the first time.
+    #[oai(
+        path = "/views/:view_id/observee",
+        method = "put",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, switch), fields(view_id = ?view_id.0))]
+    async fn switch_observee(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        switch: Json<SwitchObservee>,
+    ) -> poem::Result<Json<View>> {
+        claims.ensure_scopes(["write:views"])?;
+
+        let mut view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        if let Some(observee_id) = switch.0.observee_id {
+            if !view
+                .observees
+                .iter()
+                .any(|observee| observee.user_id == observee_id)
+            {
+                return Err(NotFoundError.into());
+            }
+        }
+
+        view.observee_id = switch.0.observee_id;
+        self.save_view(&view).await?;
+
+        Ok(Json(view.into()))
+    }
+}
@@ -0,0 +1,243 @@
+use super::{get_view, replace_cache, CourseScoped, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    endpoint::outcome as endpoint,
+    resource::{Course, Outcome, OutcomeLink, OutcomeRollup},
+    Context, Id,
+};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, OpenApi};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A student's mastery of each outcome in a course, alongside their regular grade.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MasterySummary {
+    pub course_id: Id,
+    pub current_grade: Option<String>,
+    pub current_score: Option<f64>,
+
+    pub outcome_count: usize,
+    pub assessed_count: usize,
+    pub mastered_count: usize,
+    pub outcomes: Vec<OutcomeMastery>,
+}
+
+/// A student's mastery of a single outcome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutcomeMastery {
+    pub outcome: Outcome,
+    pub group_title: String,
+    pub score: Option<f64>, // absent if the student hasn't been assessed on the outcome
+    pub result_count: u32,
+    pub mastered: Option<bool>, // absent if unassessed or the outcome has no mastery threshold
+}
+
+pub struct Api {
+    db_client: mongodb::Client,
+    views: Collection<DbView>,
+    courses: Collection<DbResource<Course>>,
+    links: Collection<DbResource<CourseScoped<OutcomeLink>>>,
+    rollups: Collection<DbResource<CourseScoped<OutcomeRollup>>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            db_client: db_client.clone(),
+            views: database.collection("views"),
+            courses: database.collection("courses"),
+            links: database.collection("outcome_links"),
+            rollups: database.collection("outcome_rollups"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the cache of a course's outcomes and the user's rollups of their results.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/outcomes/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn update_outcomes(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+        let client = view.client(self.http.clone());
+
+        let canvas_course_id = Id::new(course_id.0 as u64);
+        let upstream_pages = endpoint::list_links(&client, Context::Course(canvas_course_id))
+            .pages_owned(100)
+            .map_err(|err| Error::canvas_while("creating outcome link pagination stream", err))?
+            .map_ok(|page| CourseScoped::scope_page(canvas_course_id, page))
+            .map_err(|err| Error::canvas_while("deserializing outcome link response page", err));
+
+        replace_cache(
+            &self.db_client,
+            &self.links,
+            &view,
+            doc! { "resource.course_id": course_id.0 },
+            upstream_pages,
+        )
+        .await?;
+
+        let rollups = endpoint::list_rollups(&client, canvas_course_id, Id::new(view.student_id()))
+            .send()
+            .await
+            .map_err(|err| Error::canvas_while("fetching outcome rollups", err))?;
+
+        replace_cache(
+            &self.db_client,
+            &self.rollups,
+            &view,
+            doc! { "resource.course_id": course_id.0 },
+            stream::once(future::ok(CourseScoped::scope_page(
+                canvas_course_id,
+                rollups.rollups,
+            ))),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get a course's outcomes, each along with the group it belongs to.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/outcomes",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_outcomes(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<OutcomeLink>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let links: Vec<_> = self
+            .links
+            .find(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating outcome link cursor", err))?
+            .map_ok(|link| Any(link.resource.resource))
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting outcome links into list", err))?;
+
+        Ok(Json(links))
+    }
+
+    /// Get the user's mastery of each of a course's outcomes, alongside their current grade in the course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/mastery",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_mastery(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Any<MasterySummary>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let course = self
+            .courses
+            .find_one(
+                doc! { "view": view.cache_id(), "resource.id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("fetching course", err))?
+            .ok_or(NotFoundError)?
+            .resource;
+        let enrollment = course
+            .enrollments
+            .iter()
+            .find(|enrollment| enrollment.associated_user_id.is_none());
+
+        let rollup = self
+            .rollups
+            .find_one(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("fetching outcome rollup", err))?
+            .map(|rollup| rollup.resource.resource);
+
+        let outcomes: Vec<_> = self
+            .links
+            .find(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating outcome link cursor", err))?
+            .map_ok(|link| {
+                let link = link.resource.resource;
+                let score = rollup
+                    .as_ref()
+                    .and_then(|rollup| rollup.score(link.outcome.id));
+
+                OutcomeMastery {
+                    group_title: link.outcome_group.title,
+                    score: score.map(|score| score.score),
+                    result_count: score.map_or(0, |score| score.count),
+                    mastered: score.and_then(|score| link.outcome.is_mastered_by(score.score)),
+                    outcome: link.outcome,
+                }
+            })
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting outcome links into list", err))?;
+
+        Ok(Json(Any(MasterySummary {
+            course_id: course.id,
+            current_grade: enrollment
+                .and_then(|enrollment| enrollment.computed_current_grade.clone()),
+            current_score: enrollment.and_then(|enrollment| enrollment.computed_current_score),
+
+            outcome_count: outcomes.len(),
+            assessed_count: outcomes
+                .iter()
+                .filter(|outcome| outcome.score.is_some())
+                .count(),
+            mastered_count: outcomes
+                .iter()
+                .filter(|outcome| outcome.mastered == Some(true))
+                .count(),
+            outcomes,
+        })))
+    }
+}
@@ -0,0 +1,159 @@
+use super::get_view;
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use canvas_lms::{
+    endpoint::{
+        peer_review as endpoint,
+        submission::{self as submission_endpoint, SubmissionUpdate},
+    },
+    resource::{PeerReview, Submission},
+    Id,
+};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, Object, OpenApi};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A comment left by a peer reviewer
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct NewReviewComment {
+    pub comment: String,
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Get the peer reviews the user has been assigned for an assignment, whether or not they are complete.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/peer_reviews",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0))]
+    async fn get_assigned_reviews(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<PeerReview>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+        let user_id = Id::new(view.student_id());
+
+        let reviews: Vec<_> = endpoint::list_reviews(
+            &view.client(self.http.clone()),
+            Id::new(course_id.0 as u64),
+            Id::new(assignment_id.0 as u64),
+        )
+        .items_owned(100)
+        .map_err(|err| Error::canvas_while("creating peer review pagination stream", err))?
+        .try_filter(|review| future::ready(review.assessor_id == user_id))
+        .map_ok(Any)
+        .try_collect()
+        .await
+        .map_err(|err| Error::canvas_while("fetching peer reviews", err))?;
+
+        Ok(Json(reviews))
+    }
+
+    /// Get the peer reviews of the view's student's submission for an assignment.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/submission/peer_reviews",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0))]
+    async fn get_submission_reviews(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<PeerReview>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+        let client = view.client(self.http.clone());
+
+        let course_id = Id::new(course_id.0 as u64);
+        let assignment_id = Id::new(assignment_id.0 as u64);
+
+        let submission = submission_endpoint::get_submission(
+            &client,
+            course_id,
+            assignment_id,
+            Id::new(view.student_id()),
+        )
+        .send()
+        .await
+        .map_err(|err| Error::canvas_while("fetching submission", err))?;
+        let submission_id = submission.id.ok_or(NotFoundError)?;
+
+        let reviews: Vec<_> =
+            endpoint::list_submission_reviews(&client, course_id, assignment_id, submission_id)
+                .items_owned(100)
+                .map_err(|err| Error::canvas_while("creating peer review pagination stream", err))?
+                .map_ok(Any)
+                .try_collect()
+                .await
+                .map_err(|err| Error::canvas_while("fetching peer reviews", err))?;
+
+        Ok(Json(reviews))
+    }
+
+    /// Comment on a submission the user has been assigned to peer review, given the ID of the student who submitted it.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/peer_reviews/:user_id/comments",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, comment), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0, user_id = ?user_id.0))]
+    async fn comment_on_review(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+        user_id: Path<i64>,
+        comment: Json<NewReviewComment>,
+    ) -> poem::Result<Json<Any<Submission>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let submission = submission_endpoint::update_submission(
+            &view.client(self.http.clone()),
+            Id::new(course_id.0 as u64),
+            Id::new(assignment_id.0 as u64),
+            Id::new(user_id.0 as u64),
+        )
+        .send_json(&SubmissionUpdate::comment(comment.0.comment))
+        .await
+        .map_err(|err| Error::canvas_while("commenting on peer reviewed submission", err))?;
+
+        Ok(Json(Any(submission)))
+    }
+}
@@ -0,0 +1,261 @@
+use super::{get_view, replace_cache, CourseScoped, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    endpoint::{
+        section as section_endpoint,
+        user::{self as endpoint, RosterFilter},
+    },
+    resource::{enrollment::EnrollmentType, Section, User},
+    Id,
+};
+use futures::prelude::*;
+use mongodb::{options::FindOptions, Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{
+    param::{Path, Query},
+    payload::Json,
+    types::Any,
+    OpenApi,
+};
+use serde::{de::IntoDeserializer, Deserialize};
+use uuid::Uuid;
+
+pub struct Api {
+    db_client: mongodb::Client,
+    views: Collection<DbView>,
+    people: Collection<DbResource<CourseScoped<User>>>,
+    sections: Collection<DbResource<Section>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            db_client: db_client.clone(),
+            views: database.collection("views"),
+            people: database.collection("people"),
+            sections: database.collection("sections"),
+            http,
+        }
+    }
+}
+
+/// Escape a string so that it matches itself literally within a MongoDB regular expression.
+fn escape_regex(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "\\^$.|?*+()[]{}".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the cache of people and sections for a given course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/people/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn update_people(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+        let client = view.client(self.http.clone());
+        let canvas_course_id = Id::new(course_id.0 as u64);
+
+        let section_pages = section_endpoint::list_sections(&client, canvas_course_id)
+            .include("total_students")
+            .pages_owned(100)
+            .map_err(|err| Error::canvas_while("creating section pagination stream", err))?
+            .map_err(|err| Error::canvas_while("deserializing section response page", err));
+
+        replace_cache(
+            &self.db_client,
+            &self.sections,
+            &view,
+            doc! { "resource.course_id": course_id.0 },
+            section_pages,
+        )
+        .await?;
+
+        let people_pages =
+            endpoint::list_course_users(&client, canvas_course_id, RosterFilter::default())
+                .extend_include(["avatar_url", "enrollments", "email"])
+                .pages_owned(100)
+                .map_err(|err| Error::canvas_while("creating user pagination stream", err))?
+                .map_ok(|page| CourseScoped::scope_page(canvas_course_id, page))
+                .map_err(|err| Error::canvas_while("deserializing user response page", err));
+
+        replace_cache(
+            &self.db_client,
+            &self.people,
+            &view,
+            doc! { "resource.course_id": course_id.0 },
+            people_pages,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get the people in a course, sorted by name.
+    ///
+    /// `search` matches part of a person's name, `enrollment_type` is one of `student`, `teacher`, `ta`,
+    /// `designer` or `observer`, and `section_id` restricts the results to members of a single section.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/people",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_people(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        search: Query<Option<String>>,
+        enrollment_type: Query<Option<String>>,
+        section_id: Query<Option<i64>>,
+    ) -> poem::Result<Json<Vec<Any<User>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let enrollment_type = enrollment_type
+            .0
+            .map(|enrollment_type| {
+                EnrollmentType::deserialize(enrollment_type.as_str().into_deserializer()).map_err(
+                    |err: serde::de::value::Error| Error::invalid_parameter("enrollment_type", err),
+                )
+            })
+            .transpose()?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let mut filter = doc! { "view": view.cache_id(), "resource.course_id": course_id.0 };
+        if let Some(search) = search.0.filter(|search| !search.trim().is_empty()) {
+            let pattern = escape_regex(search.trim());
+            filter.insert(
+                "$or",
+                vec![
+                    doc! { "resource.name": { "$regex": pattern.as_str(), "$options": "i" } },
+                    doc! { "resource.sortable_name": { "$regex": pattern.as_str(), "$options": "i" } },
+                ],
+            );
+        }
+        let mut enrollment_filter = doc! {};
+        if let Some(enrollment_type) = enrollment_type {
+            enrollment_filter.insert("type", enrollment_type.as_str());
+        }
+        if let Some(section_id) = section_id.0 {
+            enrollment_filter.insert("course_section_id", section_id);
+        }
+        if !enrollment_filter.is_empty() {
+            filter.insert(
+                "resource.enrollments",
+                doc! { "$elemMatch": enrollment_filter },
+            );
+        }
+
+        let people: Vec<_> = self
+            .people
+            .find(
+                filter,
+                FindOptions::builder()
+                    .sort(doc! { "resource.sortable_name": 1 })
+                    .build(),
+            )
+            .await
+            .map_err(|err| Error::database_while("creating people cursor", err))?
+            .map_ok(|person| Any(person.resource.resource))
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting people into list", err))?;
+
+        Ok(Json(people))
+    }
+
+    /// Get a person in a course by their ID.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/people/:user_id",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, user_id = ?user_id.0))]
+    async fn get_person_by_id(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        user_id: Path<i64>,
+    ) -> poem::Result<Json<Any<User>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let person = self
+            .people
+            .find_one(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0, "resource.id": user_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("fetching person", err))?
+            .ok_or(NotFoundError)?
+            .resource
+            .resource;
+
+        Ok(Json(Any(person)))
+    }
+
+    /// Get the sections of a course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/sections",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_sections(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<Section>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let sections: Vec<_> = self
+            .sections
+            .find(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating section cursor", err))?
+            .map_ok(|section| Any(section.resource))
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting sections into list", err))?;
+
+        Ok(Json(sections))
+    }
+}
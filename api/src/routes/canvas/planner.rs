@@ -0,0 +1,143 @@
+use super::{get_view, parse_date_param};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use canvas_lms::{
+    endpoint::planner::{
+        self as endpoint, NewPlannerOverride, PlannerFilter, PlannerItemState,
+        PlannerOverrideUpdate,
+    },
+    resource::planner::{PlannableType, PlannerItem, PlannerOverride},
+    Id,
+};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{
+    param::{Path, Query},
+    payload::Json,
+    types::Any,
+    Object, OpenApi,
+};
+use serde::{de::IntoDeserializer, Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The completion state of a planner item, as set by the client
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct PlannerItemStatus {
+    pub marked_complete: bool,
+    pub dismissed: bool,
+    /// The ID of the item's `planner_override`, which is absent if it doesn't have one yet
+    pub override_id: Option<u64>,
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Get the items on the view's planner.
+    ///
+    /// Dates are given in RFC 3339 format. Set `incomplete` to only get items which are neither submitted nor marked complete.
+    #[oai(
+        path = "/views/:view_id/planner",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_planner(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        start_date: Query<Option<String>>,
+        end_date: Query<Option<String>>,
+        incomplete: Query<Option<bool>>,
+    ) -> poem::Result<Json<Vec<Any<PlannerItem>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let filter = PlannerFilter {
+            start_date: parse_date_param("start_date", start_date.0)?,
+            end_date: parse_date_param("end_date", end_date.0)?,
+            ..Default::default()
+        };
+        let state = incomplete
+            .0
+            .unwrap_or(false)
+            .then_some(PlannerItemState::IncompleteItems);
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let items: Vec<_> = endpoint::list_items(&view.client(self.http.clone()), filter, state)
+            .items_owned(100)
+            .map_err(|err| Error::canvas_while("creating planner item pagination stream", err))?
+            .map_ok(Any)
+            .try_collect()
+            .await
+            .map_err(|err| Error::canvas_while("fetching planner items", err))?;
+
+        Ok(Json(items))
+    }
+
+    /// Mark a planner item as complete or dismissed, or undo either, syncing the change to Canvas.
+    #[oai(
+        path = "/views/:view_id/planner/:plannable_type/:plannable_id",
+        method = "put",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, status), fields(view_id = ?view_id.0, plannable_type = ?plannable_type.0, plannable_id = ?plannable_id.0))]
+    async fn set_planner_item_status(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        plannable_type: Path<String>,
+        plannable_id: Path<i64>,
+        status: Json<PlannerItemStatus>,
+    ) -> poem::Result<Json<Any<PlannerOverride>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let plannable_type = PlannableType::deserialize(
+            plannable_type.0.as_str().into_deserializer(),
+        )
+        .map_err(|err: serde::de::value::Error| Error::invalid_parameter("plannable_type", err))?;
+        let plannable_id = Id::new(plannable_id.0 as u64);
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+        let client = view.client(self.http.clone());
+
+        // Canvas only allows one override per plannable, so update the existing one if there is one
+        let planner_override = match status.override_id {
+            Some(override_id) => endpoint::update_override(&client, Id::new(override_id))
+                .send_json(&PlannerOverrideUpdate {
+                    marked_complete: Some(status.marked_complete),
+                    dismissed: Some(status.dismissed),
+                })
+                .await
+                .map_err(|err| Error::canvas_while("updating planner override", err))?,
+            None => endpoint::create_override(&client)
+                .send_json(&NewPlannerOverride {
+                    plannable_type,
+                    plannable_id,
+                    marked_complete: status.marked_complete,
+                    dismissed: status.dismissed,
+                })
+                .await
+                .map_err(|err| Error::canvas_while("creating planner override", err))?,
+        };
+
+        Ok(Json(Any(planner_override)))
+    }
+}
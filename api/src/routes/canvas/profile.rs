@@ -0,0 +1,232 @@
+use super::get_view;
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use canvas_lms::{
+    endpoint::{
+        communication_channel::{self as endpoint, NotificationPreferenceUpdate},
+        user as user_endpoint,
+    },
+    resource::{
+        communication_channel::NotificationFrequency, CommunicationChannel, NotificationPreference,
+        Profile,
+    },
+    Id,
+};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, Object, OpenApi};
+use serde::{
+    de::{Error as _, IntoDeserializer},
+    Deserialize, Serialize,
+};
+use uuid::Uuid;
+
+/// A communication channel along with how often Canvas notifies it of each kind of event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelPreferences {
+    pub channel: CommunicationChannel,
+    pub preferences: Vec<NotificationPreference>,
+}
+
+/// A new notification frequency set by the client
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct NewFrequency {
+    /// One of `immediately`, `daily`, `weekly` or `never`
+    pub frequency: String,
+}
+
+impl NewFrequency {
+    fn parse(&self) -> Result<NotificationPreferenceUpdate, Error> {
+        NotificationFrequency::deserialize(self.frequency.as_str().into_deserializer())
+            .map(NotificationPreferenceUpdate::new)
+            .map_err(|err: serde::de::value::Error| Error::invalid_parameter("frequency", err))
+    }
+}
+
+/// Check that a notification or category name, which is put into the Canvas URL as is, is like `new_announcement`.
+fn parse_notification_name(name: &'static str, value: &str) -> Result<&str, Error> {
+    if !value.is_empty()
+        && value
+            .bytes()
+            .all(|byte| byte.is_ascii_lowercase() || byte == b'_')
+    {
+        Ok(value)
+    } else {
+        Err(Error::invalid_parameter(
+            name,
+            serde::de::value::Error::custom("expected only lowercase letters and underscores"),
+        ))
+    }
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Get the view's Canvas profile.
+    #[oai(
+        path = "/views/:view_id/profile",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_profile(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+    ) -> poem::Result<Json<Any<Profile>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let profile = user_endpoint::get_own_profile(&view.client(self.http.clone()))
+            .send()
+            .await
+            .map_err(|err| Error::canvas_while("fetching profile", err))?;
+
+        Ok(Json(Any(profile)))
+    }
+
+    /// Get how Canvas notifies each of the view's communication channels.
+    #[oai(
+        path = "/views/:view_id/notification_preferences",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_notification_preferences(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+    ) -> poem::Result<Json<Vec<Any<ChannelPreferences>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+        let client = view.client(self.http.clone());
+
+        let channels: Vec<_> = endpoint::list_channels(&client)
+            .items_owned(100)
+            .map_err(|err| {
+                Error::canvas_while("creating communication channel pagination stream", err)
+            })?
+            .try_collect()
+            .await
+            .map_err(|err| Error::canvas_while("fetching communication channels", err))?;
+
+        let preferences = future::try_join_all(channels.into_iter().map(|channel| {
+            endpoint::list_preferences(&client, channel.id)
+                .send()
+                .map_ok(|preferences| {
+                    Any(ChannelPreferences {
+                        channel,
+                        preferences: preferences.notification_preferences,
+                    })
+                })
+        }))
+        .await
+        .map_err(|err| Error::canvas_while("fetching notification preferences", err))?;
+
+        Ok(Json(preferences))
+    }
+
+    /// Set how often Canvas notifies a communication channel of a single kind of event, such as `new_announcement`.
+    #[oai(
+        path = "/views/:view_id/communication_channels/:channel_id/notification_preferences/:notification",
+        method = "put",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, frequency), fields(view_id = ?view_id.0, channel_id = ?channel_id.0, notification = ?notification.0))]
+    async fn set_notification_preference(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        channel_id: Path<i64>,
+        notification: Path<String>,
+        frequency: Json<NewFrequency>,
+    ) -> poem::Result<Json<Vec<Any<NotificationPreference>>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let notification = parse_notification_name("notification", &notification.0)?;
+        let update = frequency.parse()?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let preferences = endpoint::update_preference(
+            &view.client(self.http.clone()),
+            Id::new(channel_id.0 as u64),
+            notification,
+        )
+        .send_json(&update)
+        .await
+        .map_err(|err| Error::canvas_while("updating notification preference", err))?;
+
+        Ok(Json(
+            preferences
+                .notification_preferences
+                .into_iter()
+                .map(Any)
+                .collect(),
+        ))
+    }
+
+    /// Set how often Canvas notifies a communication channel of every event in a category, such as `announcement`.
+    #[oai(
+        path = "/views/:view_id/communication_channels/:channel_id/notification_categories/:category",
+        method = "put",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, frequency), fields(view_id = ?view_id.0, channel_id = ?channel_id.0, category = ?category.0))]
+    async fn set_notification_category(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        channel_id: Path<i64>,
+        category: Path<String>,
+        frequency: Json<NewFrequency>,
+    ) -> poem::Result<Json<Vec<Any<NotificationPreference>>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let category = parse_notification_name("category", &category.0)?;
+        let update = frequency.parse()?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let preferences = endpoint::update_category(
+            &view.client(self.http.clone()),
+            Id::new(channel_id.0 as u64),
+            category,
+        )
+        .send_json(&update)
+        .await
+        .map_err(|err| Error::canvas_while("updating notification category", err))?;
+
+        Ok(Json(
+            preferences
+                .notification_preferences
+                .into_iter()
+                .map(Any)
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,189 @@
+use super::{get_view, replace_cache, CourseScoped, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    endpoint::{quiz as endpoint, submission as submission_endpoint},
+    resource::{Quiz, QuizSubmission, Submission},
+    Id,
+};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, OpenApi};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The quiz linked to an assignment, along with the student's attempts at it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentQuiz {
+    pub quiz: Quiz,
+    pub submission: Option<QuizSubmission>, // the latest attempt, absent if the student hasn't started the quiz
+    pub attempts: Vec<Submission>,          // every submitted attempt, oldest first
+}
+
+pub struct Api {
+    db_client: mongodb::Client,
+    views: Collection<DbView>,
+    quizzes: Collection<DbResource<CourseScoped<Quiz>>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            db_client: db_client.clone(),
+            views: database.collection("views"),
+            quizzes: database.collection("quizzes"),
+            http,
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the quiz cache for a given course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/quizzes/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn update_quizzes(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let canvas_course_id = Id::new(course_id.0 as u64);
+        let upstream_pages =
+            endpoint::list_quizzes(&view.client(self.http.clone()), canvas_course_id)
+                .pages_owned(100)
+                .map_err(|err| Error::canvas_while("creating quiz pagination stream", err))?
+                .map_ok(|page| CourseScoped::scope_page(canvas_course_id, page))
+                .map_err(|err| Error::canvas_while("deserializing quiz response page", err));
+
+        replace_cache(
+            &self.db_client,
+            &self.quizzes,
+            &view,
+            doc! { "resource.course_id": course_id.0 },
+            upstream_pages,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get all quizzes for a course.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/quizzes",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_quizzes(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<Quiz>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let quizzes: Vec<_> = self
+            .quizzes
+            .find(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating quiz cursor", err))?
+            .map_ok(|quiz| Any(quiz.resource.resource))
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting quizzes into list", err))?;
+
+        Ok(Json(quizzes))
+    }
+
+    /// Get the quiz linked to an assignment along with the student's latest quiz submission and the score of every attempt at it.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/quiz",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0))]
+    async fn get_assignment_quiz(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+    ) -> poem::Result<Json<Any<AssignmentQuiz>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let quiz = self
+            .quizzes
+            .find_one(
+                doc! {
+                    "view": view.cache_id(),
+                    "resource.course_id": course_id.0,
+                    "resource.assignment_id": assignment_id.0,
+                },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("fetching quiz", err))?
+            .ok_or(NotFoundError)?
+            .resource
+            .resource;
+
+        let client = view.client(self.http.clone());
+        let course_id = Id::new(course_id.0 as u64);
+        let student_id = Id::new(view.student_id());
+
+        // Canvas only keeps a quiz submission for the latest attempt
+        let submission = endpoint::list_submissions(&client, course_id, quiz.id)
+            .send()
+            .await
+            .map_err(|err| Error::canvas_while("fetching quiz submissions", err))?
+            .quiz_submissions
+            .into_iter()
+            .find(|submission| submission.user_id == student_id);
+
+        // so the score of each attempt comes from the assignment submission's history instead
+        let mut attempts = submission_endpoint::get_submission(
+            &client,
+            course_id,
+            Id::new(assignment_id.0 as u64),
+            student_id,
+        )
+        .include("submission_history")
+        .send()
+        .await
+        .map_err(|err| Error::canvas_while("fetching submission history", err))?
+        .submission_history;
+        attempts.retain(|attempt| attempt.attempt.is_some());
+        attempts.sort_by_key(|attempt| attempt.attempt);
+
+        Ok(Json(Any(AssignmentQuiz {
+            quiz,
+            submission,
+            attempts,
+        })))
+    }
+}
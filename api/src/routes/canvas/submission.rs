@@ -0,0 +1,372 @@
+use super::{get_view, replace_cache, CourseScoped, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    endpoint::{
+        file::NewFileUpload,
+        submission::{self as endpoint, SubmissionUpdate},
+    },
+    resource::{FileUpload, Submission, SubmissionComment},
+    Id,
+};
+use futures::prelude::*;
+use mongodb::{options::ReplaceOptions, Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, Object, OpenApi};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The includes which make up a submission's feedback, which every cached submission has.
+const FEEDBACK_INCLUDES: [&str; 3] = [
+    "rubric_assessment",
+    "submission_comments",
+    "submission_history",
+];
+
+/// A comment on the user's own submission
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct NewComment {
+    pub text_comment: String,
+    /// Files uploaded after starting an upload with the `comments/files` endpoint
+    #[oai(default)]
+    pub file_ids: Vec<i64>,
+}
+
+/// A file to attach to a comment
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+pub struct NewCommentFile {
+    pub name: String,
+    /// In bytes
+    pub size: u64,
+    pub content_type: Option<String>,
+}
+
+pub struct Api {
+    db_client: mongodb::Client,
+    views: Collection<DbView>,
+    submissions: Collection<DbResource<CourseScoped<Submission>>>,
+
+    http: HttpClient,
+}
+
+impl Api {
+    pub fn new(database: &Database, db_client: &mongodb::Client, http: HttpClient) -> Self {
+        Self {
+            db_client: db_client.clone(),
+            views: database.collection("views"),
+            submissions: database.collection("submissions"),
+            http,
+        }
+    }
+
+    async fn find_submission(
+        &self,
+        view: &DbView,
+        course_id: i64,
+        assignment_id: i64,
+    ) -> poem::Result<Submission> {
+        Ok(self
+            .submissions
+            .find_one(
+                doc! {
+                    "view": view.cache_id(),
+                    "resource.course_id": course_id,
+                    "resource.assignment_id": assignment_id,
+                },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("fetching submission", err))?
+            .ok_or(NotFoundError)?
+            .resource
+            .resource)
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Update the cache of the user's submissions for a given course, along with their history and feedback.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/submissions/update",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn update_submissions(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<()> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let canvas_course_id = Id::new(course_id.0 as u64);
+        let upstream_pages = endpoint::list_student_submissions(
+            &view.client(self.http.clone()),
+            canvas_course_id,
+            Id::new(view.student_id()),
+        )
+        .extend_include(FEEDBACK_INCLUDES)
+        .pages_owned(100)
+        .map_err(|err| Error::canvas_while("creating submission pagination stream", err))?
+        .map_ok(|page| CourseScoped::scope_page(canvas_course_id, page))
+        .map_err(|err| Error::canvas_while("deserializing submission response page", err));
+
+        replace_cache(
+            &self.db_client,
+            &self.submissions,
+            &view,
+            doc! { "resource.course_id": course_id.0 },
+            upstream_pages,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get the user's submissions for a course, along with their rubric assessments.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/submissions",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0))]
+    async fn get_submissions(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<Submission>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let submissions: Vec<_> = self
+            .submissions
+            .find(
+                doc! { "view": view.cache_id(), "resource.course_id": course_id.0 },
+                None,
+            )
+            .await
+            .map_err(|err| Error::database_while("creating submission cursor", err))?
+            .map_ok(|submission| Any(submission.resource.resource))
+            .try_collect()
+            .await
+            .map_err(|err| Error::database_while("collecting submissions into list", err))?;
+
+        Ok(Json(submissions))
+    }
+
+    /// Get the user's submission for an assignment, along with its rubric assessment.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/submission",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0))]
+    async fn get_assignment_submission(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+    ) -> poem::Result<Json<Any<Submission>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let submission = self
+            .find_submission(&view, course_id.0, assignment_id.0)
+            .await?;
+
+        Ok(Json(Any(submission)))
+    }
+
+    /// Get every attempt at the user's submission for an assignment, oldest first.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/submission/history",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0))]
+    async fn get_submission_history(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<Submission>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let mut history = self
+            .find_submission(&view, course_id.0, assignment_id.0)
+            .await?
+            .submission_history;
+        history.sort_by_key(|attempt| attempt.attempt);
+
+        Ok(Json(history.into_iter().map(Any).collect()))
+    }
+
+    /// Get the comments on the user's submission for an assignment, oldest first.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/submission/comments",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0))]
+    async fn get_submission_comments(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+    ) -> poem::Result<Json<Vec<Any<SubmissionComment>>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let mut comments = self
+            .find_submission(&view, course_id.0, assignment_id.0)
+            .await?
+            .submission_comments;
+        comments.sort_by_key(|comment| comment.created_at);
+
+        Ok(Json(comments.into_iter().map(Any).collect()))
+    }
+
+    /// Start uploading a file to attach to a comment on the user's submission.
+    ///
+    /// The client uploads the file's contents directly to Canvas as described by the response, then passes its ID when commenting.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/submission/comments/files",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, file), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0))]
+    async fn upload_comment_file(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+        file: Json<NewCommentFile>,
+    ) -> poem::Result<Json<Any<FileUpload>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let upload = endpoint::upload_comment_file(
+            &view.client(self.http.clone()),
+            Id::new(course_id.0 as u64),
+            Id::new(assignment_id.0 as u64),
+            Id::new(view.student_id()),
+        )
+        .send_json(&NewFileUpload {
+            name: file.0.name,
+            size: file.0.size,
+            content_type: file.0.content_type,
+        })
+        .await
+        .map_err(|err| Error::canvas_while("starting comment file upload", err))?;
+
+        Ok(Json(Any(upload)))
+    }
+
+    /// Comment on the user's submission for an assignment, refreshing its cached feedback.
+    #[oai(
+        path = "/views/:view_id/courses/:course_id/assignments/:assignment_id/submission/comments",
+        method = "post",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self, comment), fields(view_id = ?view_id.0, course_id = ?course_id.0, assignment_id = ?assignment_id.0))]
+    async fn create_submission_comment(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+        course_id: Path<i64>,
+        assignment_id: Path<i64>,
+        comment: Json<NewComment>,
+    ) -> poem::Result<Json<Vec<Any<SubmissionComment>>>> {
+        claims.ensure_scopes(["write:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+        let client = view.client(self.http.clone());
+
+        let canvas_course_id = Id::new(course_id.0 as u64);
+        let canvas_assignment_id = Id::new(assignment_id.0 as u64);
+        let update = SubmissionUpdate::comment_with_files(
+            comment.0.text_comment,
+            comment
+                .0
+                .file_ids
+                .into_iter()
+                .map(|id| Id::new(id as u64))
+                .collect(),
+        );
+
+        endpoint::update_submission(
+            &client,
+            canvas_course_id,
+            canvas_assignment_id,
+            Id::new(view.student_id()),
+        )
+        .send_json(&update)
+        .await
+        .map_err(|err| Error::canvas_while("commenting on submission", err))?;
+
+        // Canvas doesn't respond with the submission's feedback, so fetch it again in full
+        let submission = endpoint::get_submission(
+            &client,
+            canvas_course_id,
+            canvas_assignment_id,
+            Id::new(view.student_id()),
+        )
+        .extend_include(FEEDBACK_INCLUDES)
+        .send()
+        .await
+        .map_err(|err| Error::canvas_while("fetching submission", err))?;
+
+        let mut comments = submission.submission_comments.clone();
+        comments.sort_by_key(|comment| comment.created_at);
+
+        self.submissions
+            .replace_one(
+                doc! {
+                    "view": view.cache_id(),
+                    "resource.course_id": course_id.0,
+                    "resource.assignment_id": assignment_id.0,
+                },
+                DbResource {
+                    view: view.cache_id(),
+                    inserted_at: bson::DateTime::now(),
+                    resource: CourseScoped {
+                        course_id: canvas_course_id,
+                        resource: submission,
+                    },
+                },
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map_err(|err| Error::database_while("caching submission", err))?;
+
+        Ok(Json(comments.into_iter().map(Any).collect()))
+    }
+}
@@ -0,0 +1,178 @@
+use super::{get_view, DbResource};
+use crate::{auth::Claims, routes::ApiTags, view::*, Error, HttpClient};
+use bson::doc;
+use canvas_lms::{
+    resource::{course::Term, Course},
+    Id,
+};
+use chrono::{DateTime, Utc};
+use futures::prelude::*;
+use mongodb::{Collection, Database};
+use poem::error::NotFoundError;
+use poem_openapi::{param::Path, payload::Json, types::Any, OpenApi};
+use serde::{Deserialize, Serialize};
+use std::{cmp::Reverse, collections::HashMap};
+use uuid::Uuid;
+
+/// A view's courses grouped by term, split by whether each term is current, past or future.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CoursesByTerm {
+    pub current: Vec<TermCourses>, // earliest start first
+    pub past: Vec<TermCourses>,    // latest end first
+    pub future: Vec<TermCourses>,  // earliest start first
+}
+
+impl CoursesByTerm {
+    /// Group terms by whether they are current, past or future as of `now`, sorting each term's courses by name.
+    ///
+    /// Terms without a start date are treated as having already started, and those without an end date as never ending.
+    fn group(terms: impl IntoIterator<Item = TermCourses>, now: DateTime<Utc>) -> Self {
+        let mut grouped = Self::default();
+        for mut term in terms {
+            term.courses.sort_by(|a, b| a.name.cmp(&b.name));
+
+            if term.end_at().is_some_and(|end_at| end_at <= now) {
+                grouped.past.push(term);
+            } else if term.start_at().is_some_and(|start_at| start_at > now) {
+                grouped.future.push(term);
+            } else {
+                grouped.current.push(term);
+            }
+        }
+
+        grouped.current.sort_by_key(TermCourses::start_at);
+        grouped.past.sort_by_key(|term| Reverse(term.end_at()));
+        grouped.future.sort_by_key(TermCourses::start_at);
+
+        grouped
+    }
+}
+
+/// The courses in a single term.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TermCourses {
+    pub term_id: Id,
+    pub term: Option<Term>, // absent on courses cached before terms were included
+    pub courses: Vec<Course>,
+}
+
+impl TermCourses {
+    fn start_at(&self) -> Option<DateTime<Utc>> {
+        self.term.as_ref().and_then(|term| term.start_at)
+    }
+
+    fn end_at(&self) -> Option<DateTime<Utc>> {
+        self.term.as_ref().and_then(|term| term.end_at)
+    }
+}
+
+pub struct Api {
+    views: Collection<DbView>,
+    courses: Collection<DbResource<Course>>,
+}
+
+impl Api {
+    pub fn new(database: &Database, _db_client: &mongodb::Client, _http: HttpClient) -> Self {
+        Self {
+            views: database.collection("views"),
+            courses: database.collection("courses"),
+        }
+    }
+}
+
+#[OpenApi]
+impl Api {
+    /// Get the view's cached courses grouped by term, split into current, past and future terms by their dates.
+    ///
+    /// Terms without a start date are treated as having already started, and those without an end date as never ending.
+    #[oai(
+        path = "/views/:view_id/terms",
+        method = "get",
+        tag = "ApiTags::Canvas"
+    )]
+    #[tracing::instrument(skip(self), fields(view_id = ?view_id.0))]
+    async fn get_courses_by_term(
+        &self,
+        claims: Claims,
+        view_id: Path<Uuid>,
+    ) -> poem::Result<Json<Any<CoursesByTerm>>> {
+        claims.ensure_scopes(["read:canvas"])?;
+
+        let view = get_view(&self.views, view_id.0.into())
+            .await?
+            .ok_or(NotFoundError)?;
+
+        let mut terms = HashMap::<Id, TermCourses>::new();
+        self.courses
+            .find(doc! { "view": view.cache_id() }, None)
+            .await
+            .map_err(|err| Error::database_while("creating course cursor", err))?
+            .try_for_each(|course| {
+                let course = course.resource;
+                terms
+                    .entry(course.enrollment_term_id)
+                    .or_insert_with(|| TermCourses {
+                        term_id: course.enrollment_term_id,
+                        term: course.term.clone(),
+                        courses: Vec::new(),
+                    })
+                    .courses
+                    .push(course);
+                future::ready(Ok(()))
+            })
+            .await
+            .map_err(|err| Error::database_while("collecting courses", err))?;
+
+        let grouped = CoursesByTerm::group(terms.into_values(), Utc::now());
+
+        Ok(Json(Any(grouped)))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn group_terms_by_dates() {
+    use chrono::{Duration, TimeZone};
+
+    let now = Utc.with_ymd_and_hms(2022, 3, 1, 12, 0, 0).unwrap();
+    let term = |id: u64, start_at: Option<i64>, end_at: Option<i64>| TermCourses {
+        term_id: Id::new(id),
+        term: Some(Term {
+            id: Id::new(id),
+            name: format!("Term {}", id),
+            start_at: start_at.map(|days| now + Duration::days(days)),
+            end_at: end_at.map(|days| now + Duration::days(days)),
+        }),
+        courses: Vec::new(),
+    };
+
+    let grouped = CoursesByTerm::group(
+        [
+            term(1, Some(-30), Some(30)),
+            term(2, Some(-200), Some(-100)),
+            term(3, Some(-100), Some(0)), // ends right now
+            term(4, Some(30), Some(120)),
+            term(5, None, None),
+            term(6, None, Some(-10)),
+            term(7, Some(10), None),
+            term(8, Some(-10), None),
+            TermCourses {
+                term_id: Id::new(9),
+                term: None,
+                courses: Vec::new(),
+            },
+        ],
+        now,
+    );
+
+    let ids = |terms: &[TermCourses]| {
+        terms
+            .iter()
+            .map(|term| u64::from(term.term_id))
+            .collect::<Vec<_>>()
+    };
+    // terms without a start date sort before those with one
+    assert_eq!(ids(&grouped.current), vec![5, 9, 1, 8]);
+    assert_eq!(ids(&grouped.past), vec![3, 6, 2]);
+    assert_eq!(ids(&grouped.future), vec![7, 4]);
+}
@@ -100,6 +100,8 @@ impl Api {
             canvas_domain: new_view.canvas_domain,
             canvas_user_id: user.id.into(),
             canvas_access_token: new_view.canvas_access_token,
+            observees: Vec::new(),
+            observee_id: None,
         };
 
         self.collection
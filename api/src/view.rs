@@ -19,6 +19,8 @@ pub struct View {
     pub canvas_domain: String,
     /// The user's Canvas ID.
     pub canvas_user_id: u64,
+    /// The Canvas ID of the observed student the view shows, if the user is an observer switched to one.
+    pub observee_id: Option<u64>,
     // /// The user's Canvas access token.
     // pub canvas_access_token: String,
 }
@@ -32,6 +34,20 @@ pub struct DbView {
     pub canvas_domain: String,
     pub canvas_user_id: u64,
     pub canvas_access_token: String,
+
+    #[serde(default)]
+    pub observees: Vec<DbObservee>,
+    #[serde(default)]
+    pub observee_id: Option<u64>,
+}
+
+/// A student observed by a view's user, such as a parent's child.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DbObservee {
+    pub user_id: u64,
+    pub name: String,
+    /// Partitions the cache so that each observee's resources are kept apart.
+    pub cache_id: bson::Uuid,
 }
 
 impl DbView {
@@ -43,6 +59,22 @@ impl DbView {
             ))
             .build(http)
     }
+
+    /// The Canvas ID of the student whose resources the view shows.
+    pub fn student_id(&self) -> u64 {
+        self.observee_id.unwrap_or(self.canvas_user_id)
+    }
+
+    /// The ID the view's cached resources are stored under, which differs for each observee it is switched to.
+    pub fn cache_id(&self) -> bson::Uuid {
+        self.observee_id
+            .and_then(|observee_id| {
+                self.observees
+                    .iter()
+                    .find(|observee| observee.user_id == observee_id)
+            })
+            .map_or(self.id, |observee| observee.cache_id)
+    }
 }
 
 impl From<DbView> for View {
@@ -52,6 +84,7 @@ impl From<DbView> for View {
             name: db_view.name,
             canvas_domain: db_view.canvas_domain,
             canvas_user_id: db_view.canvas_user_id,
+            observee_id: db_view.observee_id,
             // canvas_access_token: db_view.canvas_access_token,
         }
     }
@@ -17,7 +17,7 @@ const config: Auth0ClientOptions = {
 	domain: import.meta.env.VITE_AUTH0_DOMAIN,
 	client_id: import.meta.env.VITE_AUTH0_CLIENT_ID,
 	audience: "oil",
-	scope: "read:views write:views read:canvas",
+	scope: "read:views write:views read:canvas write:canvas write:grades",
 	cacheLocation: "localstorage"
 };
 